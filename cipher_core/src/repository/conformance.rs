@@ -0,0 +1,724 @@
+//! Backend-agnostic tests that every repository implementation must pass.
+//!
+//! Each test case is an async function taking a [`RepositoryProvider`] and panicking
+//! if the backend deviates from the behaviour documented on the repository traits.
+//! Test cases only create users, guilds and roles with fresh Discord ids and never
+//! assume the database is empty, so they can run concurrently against a shared database.
+//!
+//! Backends should use [`repository_conformance_tests!`](crate::repository_conformance_tests)
+//! to generate one `#[tokio::test]` per case.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use chrono::DateTime;
+
+use super::profile_repository::FriendCodeGame;
+use super::profile_repository::NewProfile;
+use super::profile_repository::ProfileFilter;
+use super::profile_repository::ProfileTextField;
+use super::profile_repository::ProfileRepository;
+use super::staff_role_repository::StaffRole;
+use super::staff_role_repository::StaffRoleRepository;
+use super::user_repository::NewUser;
+use super::user_repository::UserRepository;
+use super::Repository;
+use super::RepositoryProvider;
+
+/// Generates a `#[tokio::test]` for every conformance test case.
+///
+/// `$setup` is an async function taking the name of the test case and returning
+/// a `P: RepositoryProvider`, panicking if the backend can not be set up.
+/// Backends that require an external server should pass `ignore = "<reason>"`,
+/// which marks every generated test `#[ignore]` so it only runs with
+/// `cargo test -- --ignored`. The calling crate must depend on `tokio` with the
+/// `macros` and `rt-multi-thread` features.
+#[macro_export]
+macro_rules! repository_conformance_tests {
+    ($setup:path) => {
+        $crate::repository_conformance_tests!(@cases $setup, []);
+    };
+    ($setup:path, ignore = $reason:literal) => {
+        $crate::repository_conformance_tests!(@cases $setup, [ignore = $reason]);
+    };
+    (@cases $setup:path, $attrs:tt) => {
+        $crate::repository_conformance_tests!(@case $setup, $attrs;
+            insert_user_assigns_id,
+            insert_user_rejects_duplicate_discord_user_id,
+            user_lookup,
+            update_user_returns_previous,
+            insert_profile_activates_new_profile,
+            active_profile_is_scoped_to_user,
+            profile_history_is_newest_first,
+            set_active_profile_switches_active_version,
+            set_active_profile_unknown_profile,
+            staff_role_membership,
+            staff_role_set_is_idempotent,
+            staff_roles_are_scoped_to_guild,
+            users_lists_every_user,
+            insert_profile_version_preserves_metadata,
+            insert_staff_role_without_guild,
+            users_after_pages_in_id_order,
+            counts_include_inserted_rows,
+            delete_user_removes_profiles,
+            active_profiles_with_code_filters_by_game,
+            query_profiles_filters_and_paginates,
+            active_profile_values_match_prefix,
+            text_filters_ignore_non_ascii_case,
+            rollback_transaction_discards_writes,
+            commit_transaction_keeps_writes,
+            profiles_by_user_ids_groups_history,
+        );
+    };
+    (@case $setup:path, $attrs:tt;) => {};
+    (@case $setup:path, [$($attr:meta)?]; $case:ident, $($rest:ident,)*) => {
+        #[tokio::test]
+        $(#[$attr])?
+        async fn $case() {
+            let provider = $setup(stringify!($case)).await;
+            $crate::repository::conformance::$case(&provider).await;
+        }
+
+        $crate::repository_conformance_tests!(@case $setup, [$($attr)?]; $($rest,)*);
+    };
+}
+
+/// Returns a Discord snowflake that has not been returned before in this process.
+///
+/// The sequence is seeded from the current time so repeated runs against the
+/// same database do not collide with rows left behind by earlier runs.
+fn unique_discord_id() -> u64 {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+
+    let _ = NEXT.compare_exchange(0, time_seed(), Ordering::Relaxed, Ordering::Relaxed);
+    NEXT.fetch_add(1, Ordering::Relaxed)
+}
+
+fn time_seed() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos() as u64)
+        .unwrap_or(1);
+
+    // Discord ids are stored as signed 64-bit integers, so keep well below `i64::MAX`.
+    (nanos & (u64::MAX >> 4)).max(1)
+}
+
+async fn new_user_id<P: RepositoryProvider>(provider: &P) -> (i32, u64) {
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let discord_user_id = unique_discord_id();
+    let user = repo.insert_user(NewUser { discord_user_id }).await.expect("failed to insert user");
+
+    (user.id, discord_user_id)
+}
+
+fn new_profile(user_id: i32, trainer_class: &str) -> NewProfile {
+    NewProfile {
+        user_id,
+        trainer_class: Some(trainer_class.to_string()),
+        ..Default::default()
+    }
+}
+
+pub async fn insert_user_assigns_id<P: RepositoryProvider>(provider: &P) {
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let discord_user_id = unique_discord_id();
+    let first = repo.insert_user(NewUser { discord_user_id }).await.expect("failed to insert user");
+    let second = repo.insert_user(NewUser { discord_user_id: unique_discord_id() }).await.expect("failed to insert user");
+
+    assert_eq!(first.discord_user_id, discord_user_id);
+    assert_ne!(first.id, second.id);
+}
+
+pub async fn insert_user_rejects_duplicate_discord_user_id<P: RepositoryProvider>(provider: &P) {
+    let (_, discord_user_id) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let result = repo.insert_user(NewUser { discord_user_id }).await;
+
+    assert!(result.is_err(), "inserting a duplicate discord user id should fail");
+}
+
+pub async fn user_lookup<P: RepositoryProvider>(provider: &P) {
+    let (user_id, discord_user_id) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let by_id = repo.user(user_id).await.expect("failed to get user").expect("user should exist");
+    assert_eq!(by_id.id, user_id);
+    assert_eq!(by_id.discord_user_id, discord_user_id);
+
+    let by_discord_id = repo.user_by_discord_user_id(discord_user_id).await.expect("failed to get user").expect("user should exist");
+    assert_eq!(by_discord_id.id, user_id);
+
+    let missing = repo.user_by_discord_user_id(unique_discord_id()).await.expect("failed to get user");
+    assert!(missing.is_none());
+}
+
+pub async fn update_user_returns_previous<P: RepositoryProvider>(provider: &P) {
+    let (user_id, discord_user_id) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let new_discord_user_id = unique_discord_id();
+    let user = super::user_repository::User { id: user_id, discord_user_id: new_discord_user_id };
+
+    let previous = repo.update_user(user).await.expect("failed to update user").expect("user should exist");
+    assert_eq!(previous.discord_user_id, discord_user_id);
+
+    let updated = repo.user(user_id).await.expect("failed to get user").expect("user should exist");
+    assert_eq!(updated.discord_user_id, new_discord_user_id);
+
+    let missing = super::user_repository::User { id: i32::MAX, discord_user_id: unique_discord_id() };
+    assert!(repo.update_user(missing).await.expect("failed to update user").is_none());
+}
+
+pub async fn insert_profile_activates_new_profile<P: RepositoryProvider>(provider: &P) {
+    let (user_id, discord_user_id) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let first = repo.insert_profile(new_profile(user_id, "first")).await.expect("failed to insert profile");
+    assert_eq!(first.user_id, user_id);
+    assert_eq!(first.trainer_class.as_deref(), Some("first"));
+    assert!(first.is_active);
+
+    let second = repo.insert_profile(new_profile(user_id, "second")).await.expect("failed to insert profile");
+    assert_eq!(second.user_id, user_id);
+    assert!(second.is_active);
+    assert_ne!(first.id, second.id);
+
+    let first = repo.profile(first.id).await.expect("failed to get profile").expect("profile should exist");
+    assert!(!first.is_active, "inserting a profile should deactivate the previous version");
+
+    let active = repo.active_profile(user_id).await.expect("failed to get profile").expect("user should have an active profile");
+    assert_eq!(active.id, second.id);
+
+    let active = repo.active_profile_by_discord_id(discord_user_id).await.expect("failed to get profile").expect("user should have an active profile");
+    assert_eq!(active.id, second.id);
+}
+
+pub async fn active_profile_is_scoped_to_user<P: RepositoryProvider>(provider: &P) {
+    let (first_user_id, _) = new_user_id(provider).await;
+    let (second_user_id, second_discord_user_id) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let first = repo.insert_profile(new_profile(first_user_id, "first")).await.expect("failed to insert profile");
+    let second = repo.insert_profile(new_profile(second_user_id, "second")).await.expect("failed to insert profile");
+
+    let first_active = repo.active_profile(first_user_id).await.expect("failed to get profile").expect("user should have an active profile");
+    assert_eq!(first_active.id, first.id, "inserting a profile for one user should not affect another");
+
+    let second_active = repo.active_profile_by_discord_id(second_discord_user_id).await.expect("failed to get profile").expect("user should have an active profile");
+    assert_eq!(second_active.id, second.id);
+
+    let missing = repo.active_profile_by_discord_id(unique_discord_id()).await.expect("failed to get profile");
+    assert!(missing.is_none());
+}
+
+pub async fn profile_history_is_newest_first<P: RepositoryProvider>(provider: &P) {
+    let (user_id, discord_user_id) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let mut inserted = Vec::new();
+    for trainer_class in ["first", "second", "third"] {
+        let profile = repo.insert_profile(new_profile(user_id, trainer_class)).await.expect("failed to insert profile");
+        inserted.push(profile.id);
+    }
+
+    let history = repo.profiles_by_user_id(user_id).await.expect("failed to get profile history");
+    assert_eq!(history.len(), inserted.len());
+    assert!(history.iter().all(|profile| profile.user_id == user_id));
+    assert!(
+        history.windows(2).all(|pair| pair[0].created_at >= pair[1].created_at),
+        "profile history should be ordered by creation time, newest first",
+    );
+    assert_eq!(history.iter().filter(|profile| profile.is_active).count(), 1);
+
+    let mut history_ids: Vec<_> = history.iter().map(|profile| profile.id).collect();
+    let by_discord_id = repo.profiles_by_discord_id(discord_user_id).await.expect("failed to get profile history");
+    let by_discord_id_ids: Vec<_> = by_discord_id.iter().map(|profile| profile.id).collect();
+    assert_eq!(history_ids, by_discord_id_ids);
+
+    history_ids.sort();
+    inserted.sort();
+    assert_eq!(history_ids, inserted);
+
+    let missing = repo.profiles_by_discord_id(unique_discord_id()).await.expect("failed to get profile history");
+    assert!(missing.is_empty());
+}
+
+pub async fn set_active_profile_switches_active_version<P: RepositoryProvider>(provider: &P) {
+    let (user_id, _) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let first = repo.insert_profile(new_profile(user_id, "first")).await.expect("failed to insert profile");
+    let second = repo.insert_profile(new_profile(user_id, "second")).await.expect("failed to insert profile");
+
+    assert!(repo.set_active_profile(user_id, first.id).await.expect("failed to set active profile"));
+
+    let active = repo.active_profile(user_id).await.expect("failed to get profile").expect("user should have an active profile");
+    assert_eq!(active.id, first.id);
+
+    let second = repo.profile(second.id).await.expect("failed to get profile").expect("profile should exist");
+    assert!(!second.is_active);
+
+    let history = repo.profiles_by_user_id(user_id).await.expect("failed to get profile history");
+    assert_eq!(history.iter().filter(|profile| profile.is_active).count(), 1);
+}
+
+pub async fn set_active_profile_unknown_profile<P: RepositoryProvider>(provider: &P) {
+    let (user_id, _) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let profile = repo.insert_profile(new_profile(user_id, "first")).await.expect("failed to insert profile");
+
+    assert!(!repo.set_active_profile(user_id, i32::MAX).await.expect("failed to set active profile"));
+
+    let active = repo.active_profile(user_id).await.expect("failed to get profile").expect("user should have an active profile");
+    assert_eq!(active.id, profile.id, "a failed switch should leave the active profile unchanged");
+}
+
+pub async fn staff_role_membership<P: RepositoryProvider>(provider: &P) {
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let guild_id = unique_discord_id();
+    let staff_role_id = unique_discord_id();
+    let other_role_id = unique_discord_id();
+
+    repo.set_staff_role(guild_id, staff_role_id).await.expect("failed to set staff role");
+
+    assert!(repo.is_staff_role(guild_id, staff_role_id).await.expect("failed to check staff role"));
+    assert!(!repo.is_staff_role(guild_id, other_role_id).await.expect("failed to check staff role"));
+    assert_eq!(repo.staff_roles(guild_id).await.expect("failed to get staff roles"), vec![staff_role_id]);
+    assert!(repo.staff_roles_contains(guild_id, &[other_role_id, staff_role_id]).await.expect("failed to check staff roles"));
+    assert!(!repo.staff_roles_contains(guild_id, &[other_role_id]).await.expect("failed to check staff roles"));
+    assert!(!repo.staff_roles_contains(guild_id, &[]).await.expect("failed to check staff roles"));
+
+    repo.unset_staff_role(guild_id, staff_role_id).await.expect("failed to unset staff role");
+
+    assert!(!repo.is_staff_role(guild_id, staff_role_id).await.expect("failed to check staff role"));
+    assert!(repo.staff_roles(guild_id).await.expect("failed to get staff roles").is_empty());
+}
+
+pub async fn staff_role_set_is_idempotent<P: RepositoryProvider>(provider: &P) {
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let guild_id = unique_discord_id();
+    let staff_role_id = unique_discord_id();
+
+    repo.set_staff_role(guild_id, staff_role_id).await.expect("failed to set staff role");
+    repo.set_staff_role(guild_id, staff_role_id).await.expect("setting an existing staff role should succeed");
+
+    assert_eq!(repo.staff_roles(guild_id).await.expect("failed to get staff roles"), vec![staff_role_id]);
+
+    repo.unset_staff_role(guild_id, staff_role_id).await.expect("failed to unset staff role");
+    repo.unset_staff_role(guild_id, staff_role_id).await.expect("unsetting a missing staff role should succeed");
+}
+
+pub async fn staff_roles_are_scoped_to_guild<P: RepositoryProvider>(provider: &P) {
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let guild_id = unique_discord_id();
+    let other_guild_id = unique_discord_id();
+    let staff_role_id = unique_discord_id();
+
+    repo.set_staff_role(guild_id, staff_role_id).await.expect("failed to set staff role");
+
+    assert!(!repo.is_staff_role(other_guild_id, staff_role_id).await.expect("failed to check staff role"));
+    assert!(!repo.staff_roles_contains(other_guild_id, &[staff_role_id]).await.expect("failed to check staff roles"));
+    assert!(repo.staff_roles(other_guild_id).await.expect("failed to get staff roles").is_empty());
+
+    repo.unset_staff_role(other_guild_id, staff_role_id).await.expect("failed to unset staff role");
+
+    assert!(repo.is_staff_role(guild_id, staff_role_id).await.expect("unsetting in another guild should not affect this guild"));
+}
+
+pub async fn users_lists_every_user<P: RepositoryProvider>(provider: &P) {
+    let (first_user_id, _) = new_user_id(provider).await;
+    let (second_user_id, second_discord_user_id) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let users = repo.users().await.expect("failed to get users");
+
+    assert!(users.iter().any(|user| user.id == first_user_id));
+    assert!(users.iter().any(|user| user.id == second_user_id && user.discord_user_id == second_discord_user_id));
+}
+
+pub async fn insert_profile_version_preserves_metadata<P: RepositoryProvider>(provider: &P) {
+    let (user_id, _) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let older_created_at = DateTime::from_timestamp(1_700_000_000, 0).expect("timestamp should be valid");
+    let newer_created_at = DateTime::from_timestamp(1_700_000_060, 0).expect("timestamp should be valid");
+
+    let older = repo.insert_profile_version(new_profile(user_id, "older"), older_created_at, true).await.expect("failed to insert profile");
+    let newer = repo.insert_profile_version(new_profile(user_id, "newer"), newer_created_at, false).await.expect("failed to insert profile");
+
+    assert!(older.is_active);
+    assert!(!newer.is_active);
+    assert_eq!(older.created_at.timestamp(), older_created_at.timestamp());
+
+    let active = repo.active_profile(user_id).await.expect("failed to get profile").expect("user should have an active profile");
+    assert_eq!(active.id, older.id, "inserting an inactive version should not change the active profile");
+
+    let history = repo.profiles_by_user_id(user_id).await.expect("failed to get profile history");
+    let history_ids: Vec<_> = history.iter().map(|profile| profile.id).collect();
+    assert_eq!(history_ids, vec![newer.id, older.id]);
+    assert_eq!(history[0].created_at.timestamp(), newer_created_at.timestamp());
+}
+
+pub async fn insert_staff_role_without_guild<P: RepositoryProvider>(provider: &P) {
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    // A staff role without a guild applies in every guild, so keep it hidden from
+    // the test cases running concurrently against the same database.
+    repo.begin_transaction().await.expect("failed to begin transaction");
+
+    let guild_id = unique_discord_id();
+    let staff_role = StaffRole {
+        discord_role_id: unique_discord_id(),
+        discord_guild_id: None,
+    };
+
+    repo.insert_staff_role(staff_role.clone()).await.expect("failed to insert staff role");
+
+    assert!(repo.all_staff_roles().await.expect("failed to get staff roles").contains(&staff_role));
+    assert!(repo.is_staff_role(guild_id, staff_role.discord_role_id).await.expect("a staff role without a guild should apply in every guild"));
+
+    repo.set_staff_role(guild_id, staff_role.discord_role_id).await.expect("failed to set staff role");
+    repo.unset_staff_role(guild_id, staff_role.discord_role_id).await.expect("failed to unset staff role");
+
+    assert!(repo.all_staff_roles().await.expect("failed to get staff roles").contains(&staff_role), "unsetting in a guild should keep the role without a guild");
+    assert!(repo.is_staff_role(guild_id, staff_role.discord_role_id).await.expect("failed to check staff role"));
+    assert!(!repo.all_staff_roles().await.expect("failed to get staff roles").contains(&StaffRole {
+        discord_guild_id: Some(guild_id),
+        ..staff_role.clone()
+    }));
+
+    repo.rollback_transaction().await.expect("failed to roll back transaction");
+}
+
+pub async fn users_after_pages_in_id_order<P: RepositoryProvider>(provider: &P) {
+    let (first_user_id, _) = new_user_id(provider).await;
+    let (second_user_id, _) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let first_page = repo.users_after(first_user_id - 1, 1).await.expect("failed to get users");
+    let first_page_ids: Vec<_> = first_page.iter().map(|user| user.id).collect();
+    assert_eq!(first_page_ids, vec![first_user_id]);
+
+    let next_page = repo.users_after(first_user_id, 2).await.expect("failed to get users");
+    assert!(!next_page.is_empty() && next_page.len() <= 2);
+    assert!(next_page.windows(2).all(|pair| pair[0].id < pair[1].id), "users should be ordered by id");
+    assert!(next_page[0].id > first_user_id && next_page[0].id <= second_user_id);
+
+    let empty_page = repo.users_after(first_user_id, 0).await.expect("failed to get users");
+    assert!(empty_page.is_empty());
+}
+
+pub async fn counts_include_inserted_rows<P: RepositoryProvider>(provider: &P) {
+    let mut repo = provider.get().await.expect("failed to get repository");
+    let user_count = repo.user_count().await.expect("failed to count users");
+    let profile_count = repo.profile_count().await.expect("failed to count profiles");
+    let staff_role_count = repo.staff_role_count().await.expect("failed to count staff roles");
+    drop(repo);
+
+    let (user_id, _) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+    repo.insert_profile(new_profile(user_id, "first")).await.expect("failed to insert profile");
+    repo.insert_profile(new_profile(user_id, "second")).await.expect("failed to insert profile");
+    repo.set_staff_role(unique_discord_id(), unique_discord_id()).await.expect("failed to set staff role");
+
+    assert!(repo.user_count().await.expect("failed to count users") > user_count);
+    assert!(repo.profile_count().await.expect("failed to count profiles") >= profile_count + 2);
+    assert!(repo.staff_role_count().await.expect("failed to count staff roles") > staff_role_count);
+}
+
+pub async fn delete_user_removes_profiles<P: RepositoryProvider>(provider: &P) {
+    let (user_id, discord_user_id) = new_user_id(provider).await;
+    let (other_user_id, _) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    repo.insert_profile(new_profile(user_id, "first")).await.expect("failed to insert profile");
+    repo.insert_profile(new_profile(user_id, "second")).await.expect("failed to insert profile");
+    let other_profile = repo.insert_profile(new_profile(other_user_id, "other")).await.expect("failed to insert profile");
+
+    assert!(repo.delete_user(user_id).await.expect("failed to delete user"));
+
+    assert!(repo.user(user_id).await.expect("failed to get user").is_none());
+    assert!(repo.user_by_discord_user_id(discord_user_id).await.expect("failed to get user").is_none());
+    assert!(repo.profiles_by_user_id(user_id).await.expect("failed to get profile history").is_empty());
+    assert!(repo.profiles_by_discord_id(discord_user_id).await.expect("failed to get profile history").is_empty());
+
+    let other_active = repo.active_profile(other_user_id).await.expect("failed to get profile").expect("other user should keep their profile");
+    assert_eq!(other_active.id, other_profile.id);
+
+    assert!(!repo.delete_user(user_id).await.expect("failed to delete user"), "deleting a missing user should return false");
+}
+
+pub async fn active_profiles_with_code_filters_by_game<P: RepositoryProvider>(provider: &P) {
+    let (go_user_id, go_discord_user_id) = new_user_id(provider).await;
+    let (switch_user_id, switch_discord_user_id) = new_user_id(provider).await;
+    let (stale_user_id, stale_discord_user_id) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let go_profile = NewProfile {
+        pokemon_go_code: Some("1234 5678 9012".to_string()),
+        ..new_profile(go_user_id, "go")
+    };
+    let go_profile = repo.insert_profile(go_profile).await.expect("failed to insert profile");
+
+    let switch_profile = NewProfile {
+        switch_code: Some("SW-1234-5678-9012".to_string()),
+        ..new_profile(switch_user_id, "switch")
+    };
+    repo.insert_profile(switch_profile).await.expect("failed to insert profile");
+
+    // Only the inactive version of this user's profile has a code.
+    let stale_profile = NewProfile {
+        pokemon_go_code: Some("2109 8765 4321".to_string()),
+        ..new_profile(stale_user_id, "stale")
+    };
+    repo.insert_profile(stale_profile).await.expect("failed to insert profile");
+    repo.insert_profile(new_profile(stale_user_id, "current")).await.expect("failed to insert profile");
+
+    let results = repo.active_profiles_with_code(FriendCodeGame::PokemonGo).await.expect("failed to get profiles");
+
+    let go_result = results.iter().find(|(discord_user_id, _)| *discord_user_id == go_discord_user_id).expect("profile with code should be listed");
+    assert_eq!(go_result.1.id, go_profile.id);
+    assert_eq!(go_result.1.pokemon_go_code.as_deref(), Some("1234 5678 9012"));
+    assert!(!results.iter().any(|(discord_user_id, _)| *discord_user_id == switch_discord_user_id), "profiles without the code should not be listed");
+    assert!(!results.iter().any(|(discord_user_id, _)| *discord_user_id == stale_discord_user_id), "inactive profiles should not be listed");
+    assert!(results.iter().all(|(_, profile)| profile.is_active && profile.pokemon_go_code.is_some()));
+
+    let results = repo.active_profiles_with_code(FriendCodeGame::Switch).await.expect("failed to get profiles");
+    assert!(results.iter().any(|(discord_user_id, _)| *discord_user_id == switch_discord_user_id));
+}
+
+pub async fn query_profiles_filters_and_paginates<P: RepositoryProvider>(provider: &P) {
+    // A trainer class unique to this run keeps rows from other tests out of the results.
+    let trainer_class = format!("Ace Trainer {}", unique_discord_id());
+
+    let mut user_ids = Vec::new();
+    for _ in 0..3 {
+        user_ids.push(new_user_id(provider).await);
+    }
+    let (inactive_user_id, _) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let created_at = DateTime::from_timestamp(1_700_000_000, 0).expect("timestamp should be valid");
+    let later_created_at = DateTime::from_timestamp(1_700_086_400, 0).expect("timestamp should be valid");
+
+    for (index, (user_id, _)) in user_ids.iter().enumerate() {
+        let profile = NewProfile {
+            trainer_class: Some(trainer_class.clone()),
+            partner_pokemon: Some(if index == 0 { "Pikachu" } else { "Eevee" }.to_string()),
+            starting_region: Some("Kanto".to_string()),
+            switch_code: (index == 1).then(|| "SW-1234-5678-9012".to_string()),
+            ..new_profile(*user_id, "")
+        };
+        let profile_created_at = if index == 2 { later_created_at } else { created_at };
+        repo.insert_profile_version(profile, profile_created_at, true).await.expect("failed to insert profile");
+    }
+
+    let inactive_profile = NewProfile {
+        trainer_class: Some(trainer_class.clone()),
+        ..new_profile(inactive_user_id, "")
+    };
+    repo.insert_profile_version(inactive_profile, created_at, false).await.expect("failed to insert profile");
+
+    let filter = ProfileFilter {
+        trainer_class: Some(trainer_class.to_uppercase()),
+        ..Default::default()
+    };
+
+    let first_page = repo.query_profiles(&filter, 2, 0).await.expect("failed to query profiles");
+    let second_page = repo.query_profiles(&filter, 2, 2).await.expect("failed to query profiles");
+    assert_eq!(first_page.total, 3, "text filters should ignore case and skip inactive profiles");
+    assert_eq!(second_page.total, 3);
+
+    let discord_user_ids: Vec<_> = first_page.profiles.iter().chain(&second_page.profiles).map(|(discord_user_id, _)| *discord_user_id).collect();
+    let expected_discord_user_ids: Vec<_> = user_ids.iter().map(|(_, discord_user_id)| *discord_user_id).collect();
+    assert_eq!(discord_user_ids, expected_discord_user_ids, "pages should be ordered by user without overlapping");
+
+    let filter = ProfileFilter {
+        trainer_class: Some(trainer_class.clone()),
+        partner_pokemon: Some("eevee".to_string()),
+        starting_region: Some("kanto".to_string()),
+        ..Default::default()
+    };
+    let page = repo.query_profiles(&filter, 10, 0).await.expect("failed to query profiles");
+    assert_eq!(page.total, 2);
+    assert!(page.profiles.iter().all(|(_, profile)| profile.partner_pokemon.as_deref() == Some("Eevee")));
+
+    let filter = ProfileFilter {
+        trainer_class: Some(trainer_class.clone()),
+        has_code_for_game: Some(FriendCodeGame::Switch),
+        ..Default::default()
+    };
+    let page = repo.query_profiles(&filter, 10, 0).await.expect("failed to query profiles");
+    assert_eq!(page.profiles.iter().map(|(discord_user_id, _)| *discord_user_id).collect::<Vec<_>>(), vec![user_ids[1].1]);
+
+    let filter = ProfileFilter {
+        trainer_class: Some(trainer_class.clone()),
+        created_after: DateTime::from_timestamp(1_700_003_600, 0),
+        ..Default::default()
+    };
+    let page = repo.query_profiles(&filter, 10, 0).await.expect("failed to query profiles");
+    assert_eq!(page.profiles.iter().map(|(discord_user_id, _)| *discord_user_id).collect::<Vec<_>>(), vec![user_ids[2].1]);
+
+    let page = repo.query_profiles(&filter, 10, 5).await.expect("failed to query profiles");
+    assert_eq!(page.total, 1);
+    assert!(page.profiles.is_empty(), "offsets past the end should return an empty page");
+
+    let filter = ProfileFilter {
+        trainer_class: Some(trainer_class.clone()),
+        discord_user_ids: Some(vec![user_ids[2].1, user_ids[0].1, unique_discord_id()]),
+        ..Default::default()
+    };
+    let page = repo.query_profiles(&filter, 1, 0).await.expect("failed to query profiles");
+    assert_eq!(page.total, 2, "only profiles of the listed users should match");
+    assert_eq!(page.profiles.iter().map(|(discord_user_id, _)| *discord_user_id).collect::<Vec<_>>(), vec![user_ids[0].1]);
+
+    let filter = ProfileFilter {
+        trainer_class: Some(trainer_class.clone()),
+        discord_user_ids: Some(Vec::new()),
+        ..Default::default()
+    };
+    let page = repo.query_profiles(&filter, 10, 0).await.expect("failed to query profiles");
+    assert_eq!(page.total, 0, "an empty list of users should match no profiles");
+}
+
+pub async fn active_profile_values_match_prefix<P: RepositoryProvider>(provider: &P) {
+    let prefix = format!("Nat{}", unique_discord_id());
+    let values = [format!("{} Bold", prefix), format!("{} Bold", prefix), format!("{}_Calm", prefix), format!("{}xHasty", prefix)];
+
+    let mut user_ids = Vec::new();
+    for value in &values {
+        let (user_id, discord_user_id) = new_user_id(provider).await;
+        let mut repo = provider.get().await.expect("failed to get repository");
+        let profile = NewProfile {
+            nature: Some(value.clone()),
+            ..new_profile(user_id, "values")
+        };
+        repo.insert_profile(profile).await.expect("failed to insert profile");
+        user_ids.push((user_id, discord_user_id));
+    }
+
+    // Inactive values should not be suggested.
+    let mut repo = provider.get().await.expect("failed to get repository");
+    let inactive_profile = NewProfile {
+        nature: Some(format!("{} Gentle", prefix)),
+        ..new_profile(user_ids[0].0, "values")
+    };
+    repo.insert_profile_version(inactive_profile, DateTime::from_timestamp(1_700_000_000, 0).expect("timestamp should be valid"), false).await.expect("failed to insert profile");
+
+    let mut results = repo.active_profile_values(ProfileTextField::Nature, &prefix.to_uppercase(), 10).await.expect("failed to get values");
+    results.sort();
+    assert_eq!(results, vec![format!("{} Bold", prefix), format!("{}_Calm", prefix), format!("{}xHasty", prefix)]);
+
+    let results = repo.active_profile_values(ProfileTextField::Nature, &format!("{}_", prefix), 10).await.expect("failed to get values");
+    assert_eq!(results, vec![format!("{}_Calm", prefix)], "wildcards in the prefix should be matched literally");
+
+    let results = repo.active_profile_values(ProfileTextField::Nature, &prefix, 1).await.expect("failed to get values");
+    assert_eq!(results.len(), 1);
+
+    let filter = ProfileFilter {
+        nature: Some(format!("{} bold", prefix)),
+        ..Default::default()
+    };
+    let page = repo.query_profiles(&filter, 10, 0).await.expect("failed to query profiles");
+    let discord_user_ids: Vec<_> = page.profiles.iter().map(|(discord_user_id, _)| *discord_user_id).collect();
+    assert_eq!(discord_user_ids, vec![user_ids[0].1, user_ids[1].1]);
+}
+
+pub async fn text_filters_ignore_non_ascii_case<P: RepositoryProvider>(provider: &P) {
+    let (user_id, discord_user_id) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let region = format!("Ålola Éire {}", unique_discord_id());
+    let profile = NewProfile {
+        starting_region: Some(region.clone()),
+        ..new_profile(user_id, "unicode")
+    };
+    repo.insert_profile(profile).await.expect("failed to insert profile");
+
+    let filter = ProfileFilter {
+        starting_region: Some(region.to_uppercase()),
+        ..Default::default()
+    };
+    let page = repo.query_profiles(&filter, 10, 0).await.expect("failed to query profiles");
+    let discord_user_ids: Vec<_> = page.profiles.iter().map(|(discord_user_id, _)| *discord_user_id).collect();
+    assert_eq!(discord_user_ids, vec![discord_user_id]);
+
+    let results = repo.active_profile_values(ProfileTextField::StartingRegion, &region.to_lowercase(), 10).await.expect("failed to get values");
+    assert_eq!(results, vec![region]);
+}
+
+pub async fn rollback_transaction_discards_writes<P: RepositoryProvider>(provider: &P) {
+    let (user_id, _) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let guild_id = unique_discord_id();
+    let discord_user_id = unique_discord_id();
+
+    repo.begin_transaction().await.expect("failed to begin transaction");
+    repo.insert_user(NewUser { discord_user_id }).await.expect("failed to insert user");
+    repo.insert_profile(new_profile(user_id, "rolled back")).await.expect("failed to insert profile");
+    repo.set_staff_role(guild_id, unique_discord_id()).await.expect("failed to set staff role");
+    repo.rollback_transaction().await.expect("failed to roll back transaction");
+
+    assert!(repo.user_by_discord_user_id(discord_user_id).await.expect("failed to get user").is_none());
+    assert!(repo.active_profile(user_id).await.expect("failed to get profile").is_none());
+    assert!(repo.staff_roles(guild_id).await.expect("failed to get staff roles").is_empty());
+}
+
+pub async fn commit_transaction_keeps_writes<P: RepositoryProvider>(provider: &P) {
+    let discord_user_id = unique_discord_id();
+    let dropped_discord_user_id = unique_discord_id();
+
+    {
+        let mut repo = provider.get().await.expect("failed to get repository");
+
+        repo.begin_transaction().await.expect("failed to begin transaction");
+        repo.insert_user(NewUser { discord_user_id }).await.expect("failed to insert user");
+        repo.commit_transaction().await.expect("failed to commit transaction");
+    }
+
+    {
+        let mut repo = provider.get().await.expect("failed to get repository");
+
+        repo.begin_transaction().await.expect("failed to begin transaction");
+        repo.insert_user(NewUser { discord_user_id: dropped_discord_user_id }).await.expect("failed to insert user");
+    }
+
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    assert!(repo.user_by_discord_user_id(discord_user_id).await.expect("failed to get user").is_some());
+    assert!(
+        repo.user_by_discord_user_id(dropped_discord_user_id).await.expect("failed to get user").is_none(),
+        "dropping a repository should discard its open transaction",
+    );
+}
+
+pub async fn profiles_by_user_ids_groups_history<P: RepositoryProvider>(provider: &P) {
+    let (first_user_id, _) = new_user_id(provider).await;
+    let (second_user_id, _) = new_user_id(provider).await;
+    let (other_user_id, _) = new_user_id(provider).await;
+    let mut repo = provider.get().await.expect("failed to get repository");
+
+    let created_at = |offset: i64| DateTime::from_timestamp(1_700_000_000 + offset, 0).expect("timestamp should be valid");
+
+    let first_older = repo.insert_profile_version(new_profile(first_user_id, "older"), created_at(0), false).await.expect("failed to insert profile");
+    let second = repo.insert_profile_version(new_profile(second_user_id, "only"), created_at(30), true).await.expect("failed to insert profile");
+    let first_newer = repo.insert_profile_version(new_profile(first_user_id, "newer"), created_at(60), true).await.expect("failed to insert profile");
+    repo.insert_profile(new_profile(other_user_id, "other")).await.expect("failed to insert profile");
+
+    let profiles = repo.profiles_by_user_ids(&[second_user_id, first_user_id]).await.expect("failed to get profiles");
+    let profile_ids: Vec<_> = profiles.iter().map(|profile| profile.id).collect();
+    assert_eq!(profile_ids, vec![first_newer.id, first_older.id, second.id]);
+
+    assert!(repo.profiles_by_user_ids(&[]).await.expect("failed to get profiles").is_empty());
+}
@@ -4,6 +4,8 @@ use profile_repository::ProfileRepository;
 use staff_role_repository::StaffRoleRepository;
 use user_repository::UserRepository;
 
+#[cfg(feature = "conformance")]
+pub mod conformance;
 pub mod profile_repository;
 pub mod staff_role_repository;
 pub mod user_repository;
@@ -19,6 +21,7 @@ pub trait RepositoryProvider {
     async fn get(&self) -> Result<Self::Repository<'_>, RepositoryError<Self::BackendError>>;
 }
 
+#[async_trait::async_trait]
 pub trait Repository
 where
     Self: ProfileRepository<BackendError = <Self as Repository>::BackendError>,
@@ -26,6 +29,16 @@ where
     Self: UserRepository<BackendError = <Self as Repository>::BackendError>,
 {
     type BackendError: std::error::Error;
+
+    /// Starts a transaction spanning every following operation of this repository.
+    ///
+    /// The writes made in the transaction are discarded if it is rolled back or if
+    /// the repository is dropped before the transaction is committed.
+    async fn begin_transaction(&mut self) -> Result<(), RepositoryError<<Self as Repository>::BackendError>>;
+
+    async fn commit_transaction(&mut self) -> Result<(), RepositoryError<<Self as Repository>::BackendError>>;
+
+    async fn rollback_transaction(&mut self) -> Result<(), RepositoryError<<Self as Repository>::BackendError>>;
 }
 
 #[derive(Debug)]
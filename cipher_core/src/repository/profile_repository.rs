@@ -83,6 +83,16 @@ pub trait ProfileRepository {
     /// * `Err(RepositoryError<Self::BackendError>)` - If an error occurs.
     async fn profiles_by_discord_id(&mut self, discord_user_id: u64) -> Result<Vec<Profile>, RepositoryError<Self::BackendError>>;
 
+    /// Retrieves the full profile history of several users at once.
+    ///
+    /// # Arguments
+    /// * `user_ids` - The IDs of the users whose profile histories are being retrieved.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Profile>)` - Every version of the users' profiles, ordered by user and newest first within a user.
+    /// * `Err(RepositoryError<Self::BackendError>)` - If an error occurs.
+    async fn profiles_by_user_ids(&mut self, user_ids: &[i32]) -> Result<Vec<Profile>, RepositoryError<Self::BackendError>>;
+
     /// Sets an profile version as active.
     ///
     /// This function marks `profile_id` as the active profile
@@ -97,6 +107,67 @@ pub trait ProfileRepository {
     /// * `Ok(true)` - If the operation was successful.
     /// * `Err(RepositoryError<Self::BackendError>)` - If the operation fails.
     async fn set_active_profile(&mut self, user_id: i32, profile_id: i32) -> Result<bool, RepositoryError<Self::BackendError>>;
+
+    /// Inserts a profile version exactly as given.
+    ///
+    /// Unlike [`insert_profile`](ProfileRepository::insert_profile), this does not change
+    /// which profile is active for the user. It is intended for restoring archived
+    /// profile history, where the caller is responsible for activating at most one
+    /// profile per user.
+    ///
+    /// # Arguments
+    /// * `new_profile` - The profile data to insert.
+    /// * `created_at` - The time the profile version was originally created.
+    /// * `is_active` - Whether the profile version is the user's active profile.
+    ///
+    /// # Returns
+    /// * `Ok(Profile)` - The inserted profile with its assigned ID.
+    /// * `Err(RepositoryError<Self::BackendError>)` - If the operation fails.
+    async fn insert_profile_version(&mut self, new_profile: NewProfile, created_at: DateTime<Utc>, is_active: bool) -> Result<Profile, RepositoryError<Self::BackendError>>;
+
+    /// Counts every profile version of every user.
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - The number of stored profile versions.
+    /// * `Err(RepositoryError<Self::BackendError>)` - If an error occurs.
+    async fn profile_count(&mut self) -> Result<u64, RepositoryError<Self::BackendError>>;
+
+    /// Retrieves every active profile that has a friend code for the given game.
+    ///
+    /// # Arguments
+    /// * `game` - The game the friend code must be set for.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<(u64, Profile)>)` - The matching profiles paired with the Discord user ID of their owner, ordered by user.
+    /// * `Err(RepositoryError<Self::BackendError>)` - If an error occurs.
+    async fn active_profiles_with_code(&mut self, game: FriendCodeGame) -> Result<Vec<(u64, Profile)>, RepositoryError<Self::BackendError>>;
+
+    /// Retrieves a page of active profiles matching a filter.
+    ///
+    /// Text filters match whole values, ignoring case. Results are ordered by user,
+    /// so consecutive pages neither overlap nor skip profiles while the data is unchanged.
+    ///
+    /// # Arguments
+    /// * `filter` - The conditions every returned profile must satisfy.
+    /// * `limit` - The maximum number of profiles to return.
+    /// * `offset` - The number of matching profiles to skip.
+    ///
+    /// # Returns
+    /// * `Ok(ProfilePage)` - The matching profiles along with the total number of matches.
+    /// * `Err(RepositoryError<Self::BackendError>)` - If an error occurs.
+    async fn query_profiles(&mut self, filter: &ProfileFilter, limit: u32, offset: u64) -> Result<ProfilePage, RepositoryError<Self::BackendError>>;
+
+    /// Retrieves the distinct values of a text field across all active profiles.
+    ///
+    /// # Arguments
+    /// * `field` - The field to list the values of.
+    /// * `prefix` - Only values starting with this text, ignoring case, are returned.
+    /// * `limit` - The maximum number of values to return.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<String>)` - The matching values in ascending order.
+    /// * `Err(RepositoryError<Self::BackendError>)` - If an error occurs.
+    async fn active_profile_values(&mut self, field: ProfileTextField, prefix: &str, limit: u32) -> Result<Vec<String>, RepositoryError<Self::BackendError>>;
 }
 
 #[derive(Debug, Clone)]
@@ -143,7 +214,86 @@ pub struct NewProfile {
     pub switch_code: Option<String>,
 }
 
+/// A game that profiles can store a friend code for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FriendCodeGame {
+    PokemonGo,
+    PokemonPocket,
+    Switch,
+}
+
+/// A free text profile field that profiles can be searched by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileTextField {
+    TrainerClass,
+    Nature,
+    PartnerPokemon,
+    StartingRegion,
+}
+
+/// Conditions used to select active profiles. Unset fields match every profile.
+#[derive(Debug, Clone, Default)]
+pub struct ProfileFilter {
+    pub partner_pokemon: Option<String>,
+    pub starting_region: Option<String>,
+    pub trainer_class: Option<String>,
+    pub nature: Option<String>,
+    pub has_code_for_game: Option<FriendCodeGame>,
+    pub created_after: Option<DateTime<Utc>>,
+    /// Only match the profiles of these Discord users, e.g. the members of a guild.
+    pub discord_user_ids: Option<Vec<u64>>,
+}
+
+/// A page of profiles returned by [`ProfileRepository::query_profiles`].
+#[derive(Debug, Clone)]
+pub struct ProfilePage {
+    /// The profiles on the page paired with the Discord user ID of their owner.
+    pub profiles: Vec<(u64, Profile)>,
+    /// The number of profiles matching the filter across all pages.
+    pub total: u64,
+}
+
+impl ProfileFilter {
+    /// Returns whether an active profile satisfies the filter.
+    pub fn matches(&self, profile: &Profile) -> bool {
+        fn matches_text(expected: &Option<String>, actual: &Option<String>) -> bool {
+            match (expected, actual) {
+                (None, _) => true,
+                (Some(expected), Some(actual)) => expected.to_lowercase() == actual.to_lowercase(),
+                (Some(_), None) => false,
+            }
+        }
+
+        profile.is_active
+            && matches_text(&self.partner_pokemon, &profile.partner_pokemon)
+            && matches_text(&self.starting_region, &profile.starting_region)
+            && matches_text(&self.trainer_class, &profile.trainer_class)
+            && matches_text(&self.nature, &profile.nature)
+            && self.has_code_for_game.is_none_or(|game| profile.friend_code(game).is_some())
+            && self.created_after.is_none_or(|created_after| profile.created_at > created_after)
+    }
+}
+
 impl Profile {
+    /// Returns the friend code stored for the given game.
+    pub fn friend_code(&self, game: FriendCodeGame) -> Option<&str> {
+        match game {
+            FriendCodeGame::PokemonGo => self.pokemon_go_code.as_deref(),
+            FriendCodeGame::PokemonPocket => self.pokemon_pocket_code.as_deref(),
+            FriendCodeGame::Switch => self.switch_code.as_deref(),
+        }
+    }
+
+    /// Returns the value stored in a text field.
+    pub fn text_field(&self, field: ProfileTextField) -> Option<&str> {
+        match field {
+            ProfileTextField::TrainerClass => self.trainer_class.as_deref(),
+            ProfileTextField::Nature => self.nature.as_deref(),
+            ProfileTextField::PartnerPokemon => self.partner_pokemon.as_deref(),
+            ProfileTextField::StartingRegion => self.starting_region.as_deref(),
+        }
+    }
+
     pub fn into_new(self) -> NewProfile {
         NewProfile {
             user_id: self.user_id,
@@ -1,16 +1,33 @@
 use super::RepositoryError;
 
+/// A repository trait for managing the roles that grant access to staff commands.
+///
+/// Staff roles are scoped to the guild they were added in. Roles stored before
+/// staff roles were scoped to guilds have no guild and apply in every guild.
+/// Unsetting a role in a guild only removes the role stored for that guild.
 #[async_trait::async_trait]
 pub trait StaffRoleRepository {
     type BackendError: std::error::Error;
 
-    async fn is_staff_role(&mut self, id: u64) -> Result<bool, RepositoryError<Self::BackendError>>;
+    async fn is_staff_role(&mut self, guild_id: u64, id: u64) -> Result<bool, RepositoryError<Self::BackendError>>;
 
-    async fn staff_roles(&mut self) -> Result<Vec<u64>, RepositoryError<Self::BackendError>>;
+    async fn staff_roles(&mut self, guild_id: u64) -> Result<Vec<u64>, RepositoryError<Self::BackendError>>;
 
-    async fn staff_roles_contains(&mut self, ids: &[u64]) -> Result<bool, RepositoryError<Self::BackendError>>;
+    async fn staff_roles_contains(&mut self, guild_id: u64, ids: &[u64]) -> Result<bool, RepositoryError<Self::BackendError>>;
 
-    async fn set_staff_role(&mut self, id: u64) -> Result<(), RepositoryError<Self::BackendError>>;
+    async fn set_staff_role(&mut self, guild_id: u64, id: u64) -> Result<(), RepositoryError<Self::BackendError>>;
 
-    async fn unset_staff_role(&mut self, id: u64) -> Result<(), RepositoryError<Self::BackendError>>;
+    async fn unset_staff_role(&mut self, guild_id: u64, id: u64) -> Result<(), RepositoryError<Self::BackendError>>;
+
+    async fn all_staff_roles(&mut self) -> Result<Vec<StaffRole>, RepositoryError<Self::BackendError>>;
+
+    async fn insert_staff_role(&mut self, staff_role: StaffRole) -> Result<(), RepositoryError<Self::BackendError>>;
+
+    async fn staff_role_count(&mut self) -> Result<u64, RepositoryError<Self::BackendError>>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaffRole {
+    pub discord_role_id: u64,
+    pub discord_guild_id: Option<u64>,
 }
@@ -11,6 +11,18 @@ pub trait UserRepository {
     async fn insert_user(&mut self, new_user: NewUser) -> Result<User, RepositoryError<Self::BackendError>>;
 
     async fn update_user(&mut self, user: User) -> Result<Option<User>, RepositoryError<Self::BackendError>>;
+
+    /// Deletes a user along with every version of their profile.
+    ///
+    /// Returns `false` if no user with the given id exists.
+    async fn delete_user(&mut self, id: i32) -> Result<bool, RepositoryError<Self::BackendError>>;
+
+    async fn users(&mut self) -> Result<Vec<User>, RepositoryError<Self::BackendError>>;
+
+    /// Returns up to `limit` users with an id greater than `after_id`, ordered by id.
+    async fn users_after(&mut self, after_id: i32, limit: u32) -> Result<Vec<User>, RepositoryError<Self::BackendError>>;
+
+    async fn user_count(&mut self) -> Result<u64, RepositoryError<Self::BackendError>>;
 }
 
 pub struct User {
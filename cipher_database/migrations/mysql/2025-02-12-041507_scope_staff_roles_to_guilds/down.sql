@@ -0,0 +1,15 @@
+DROP INDEX staff_roles_discord_guild_id_discord_role_id ON staff_roles;
+DROP INDEX staff_roles_discord_role_id ON staff_roles;
+
+DELETE FROM staff_roles
+WHERE id NOT IN (
+    SELECT id FROM (
+        SELECT MIN(id) AS id
+        FROM staff_roles
+        GROUP BY discord_role_id
+    ) AS subquery
+);
+
+CREATE UNIQUE INDEX staff_roles_discord_role_id ON staff_roles(discord_role_id);
+
+ALTER TABLE staff_roles DROP COLUMN discord_guild_id;
@@ -0,0 +1,6 @@
+ALTER TABLE staff_roles ADD COLUMN discord_guild_id BIGINT;
+
+DROP INDEX staff_roles_discord_role_id;
+
+CREATE UNIQUE INDEX staff_roles_discord_guild_id_discord_role_id ON staff_roles(discord_guild_id, discord_role_id);
+CREATE INDEX staff_roles_discord_role_id ON staff_roles(discord_role_id);
@@ -0,0 +1,13 @@
+DROP INDEX staff_roles_discord_guild_id_discord_role_id;
+DROP INDEX staff_roles_discord_role_id;
+
+DELETE FROM staff_roles
+WHERE id NOT IN (
+    SELECT MIN(id)
+    FROM staff_roles
+    GROUP BY discord_role_id
+);
+
+CREATE UNIQUE INDEX staff_roles_discord_role_id ON staff_roles(discord_role_id);
+
+ALTER TABLE staff_roles DROP COLUMN discord_guild_id;
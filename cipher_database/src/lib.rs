@@ -1,12 +1,23 @@
+#[cfg(feature = "memory")]
+pub mod memory;
+#[cfg(any(feature = "mysql", feature = "postgres", feature = "sqlite"))]
+mod migration;
 #[cfg(feature = "mysql")]
 pub mod mysql;
 #[cfg(feature = "postgres")]
 pub mod postgres;
 #[cfg(feature = "sqlite")]
 pub mod sqlite;
+#[cfg(any(feature = "mysql", feature = "postgres", feature = "sqlite"))]
+mod sql_function;
+
+#[cfg(any(feature = "mysql", feature = "postgres", feature = "sqlite"))]
+pub use migration::MigrationStatus;
 
 #[derive(Clone, Debug)]
 pub enum DatabaseDialect {
+    #[cfg(feature = "memory")]
+    Memory,
     #[cfg(feature = "mysql")]
     Mysql,
     #[cfg(feature = "postgres")]
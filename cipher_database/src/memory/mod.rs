@@ -0,0 +1,11 @@
+use repository::MemoryRepositoryProvider;
+
+pub mod repository;
+
+/// Creates a repository provider backed by an empty in-memory database.
+///
+/// No connection is established and no migrations are required. All data is
+/// lost when the provider is dropped.
+pub fn repository_provider() -> MemoryRepositoryProvider {
+    MemoryRepositoryProvider::new()
+}
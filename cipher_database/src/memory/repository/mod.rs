@@ -0,0 +1,142 @@
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+
+use cipher_core::repository::profile_repository::Profile;
+use cipher_core::repository::Repository;
+use cipher_core::repository::RepositoryError;
+use cipher_core::repository::RepositoryProvider;
+
+use crate::BackendError;
+
+mod profile_repository;
+mod staff_role_repository;
+mod user_repository;
+
+/// The tables of an in-memory database.
+///
+/// Row ids are assigned from per-table counters starting at 1, mirroring the
+/// auto-incrementing primary keys used by the SQL dialects.
+#[derive(Default, Clone)]
+struct MemoryDatabase {
+    users: Vec<MemoryUser>,
+    profiles: Vec<Profile>,
+    staff_roles: Vec<MemoryStaffRole>,
+
+    last_user_id: i32,
+    last_profile_id: i32,
+    last_staff_role_id: i32,
+}
+
+#[derive(Clone)]
+struct MemoryUser {
+    id: i32,
+    discord_user_id: u64,
+}
+
+#[derive(Clone)]
+struct MemoryStaffRole {
+    #[allow(unused)]
+    id: i32,
+    discord_role_id: u64,
+    discord_guild_id: Option<u64>,
+}
+
+impl MemoryStaffRole {
+    /// Whether the role applies in the guild, including roles stored without a guild.
+    fn applies_in(&self, guild_id: u64) -> bool {
+        self.discord_guild_id.is_none_or(|discord_guild_id| discord_guild_id == guild_id)
+    }
+}
+
+pub struct MemoryRepository<'a> {
+    database: &'a Mutex<MemoryDatabase>,
+    /// Copies of the database taken when each open transaction began, outermost first.
+    ///
+    /// Rolling back restores the whole database, discarding writes made through
+    /// other repositories while the transaction was open.
+    snapshots: Vec<MemoryDatabase>,
+}
+
+impl<'a> MemoryRepository<'a> {
+    fn new(database: &'a Mutex<MemoryDatabase>) -> Self {
+        Self { database, snapshots: Vec::new() }
+    }
+
+    /// Locks the database for the duration of a single operation.
+    ///
+    /// Every operation leaves the database in a consistent state before releasing
+    /// the lock, so a poisoned lock is recovered rather than propagated.
+    fn database(&self) -> MutexGuard<'_, MemoryDatabase> {
+        self.database.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository for MemoryRepository<'_> {
+    type BackendError = BackendError;
+
+    async fn begin_transaction(&mut self) -> Result<(), RepositoryError<BackendError>> {
+        let snapshot = self.database().clone();
+        self.snapshots.push(snapshot);
+
+        Ok(())
+    }
+
+    async fn commit_transaction(&mut self) -> Result<(), RepositoryError<BackendError>> {
+        match self.snapshots.pop() {
+            Some(_) => Ok(()),
+            None => Err(no_transaction()),
+        }
+    }
+
+    async fn rollback_transaction(&mut self) -> Result<(), RepositoryError<BackendError>> {
+        let snapshot = self.snapshots.pop().ok_or_else(no_transaction)?;
+        *self.database() = snapshot;
+
+        Ok(())
+    }
+}
+
+impl Drop for MemoryRepository<'_> {
+    fn drop(&mut self) {
+        let snapshots = std::mem::take(&mut self.snapshots);
+
+        if let Some(snapshot) = snapshots.into_iter().next() {
+            *self.database() = snapshot;
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct MemoryRepositoryProvider {
+    database: Mutex<MemoryDatabase>,
+}
+
+impl MemoryRepositoryProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl RepositoryProvider for MemoryRepositoryProvider {
+    type BackendError = BackendError;
+
+    type Repository<'a> = MemoryRepository<'a>;
+
+    async fn get(&self) -> Result<Self::Repository<'_>, RepositoryError<Self::BackendError>> {
+        Ok(MemoryRepository::new(&self.database))
+    }
+}
+
+/// Builds the error a SQL backend would report when a constraint is violated.
+fn constraint_violation(kind: diesel::result::DatabaseErrorKind, message: &str) -> RepositoryError<BackendError> {
+    let error = diesel::result::Error::DatabaseError(kind, Box::new(message.to_string()));
+    RepositoryError(BackendError::from(error))
+}
+
+/// Builds the error a SQL backend would report when no transaction is open.
+fn no_transaction() -> RepositoryError<BackendError> {
+    RepositoryError(BackendError::from(diesel::result::Error::NotInTransaction))
+}
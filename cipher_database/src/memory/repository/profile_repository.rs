@@ -0,0 +1,257 @@
+use chrono::DateTime;
+use chrono::Utc;
+use cipher_core::repository::profile_repository::FriendCodeGame;
+use cipher_core::repository::profile_repository::NewProfile;
+use cipher_core::repository::profile_repository::Profile;
+use cipher_core::repository::profile_repository::ProfileFilter;
+use cipher_core::repository::profile_repository::ProfilePage;
+use cipher_core::repository::profile_repository::ProfileTextField;
+use cipher_core::repository::profile_repository::ProfileRepository;
+use cipher_core::repository::RepositoryError;
+use diesel::result::DatabaseErrorKind;
+
+use crate::BackendError;
+
+use super::constraint_violation;
+use super::MemoryDatabase;
+use super::MemoryRepository;
+
+#[async_trait::async_trait]
+impl ProfileRepository for MemoryRepository<'_> {
+    type BackendError = BackendError;
+
+    async fn insert_profile(&mut self, new_profile: NewProfile) -> Result<Profile, RepositoryError<Self::BackendError>> {
+        let mut database = self.database();
+
+        let profile = database.new_profile(new_profile)?;
+
+        database.profiles
+            .iter_mut()
+            .filter(|other| other.user_id == profile.user_id)
+            .for_each(|other| other.is_active = false);
+
+        database.profiles.push(profile.clone());
+
+        Ok(profile)
+    }
+
+    async fn profile(&mut self, id: i32) -> Result<Option<Profile>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let option_profile = database.profiles
+            .iter()
+            .find(|profile| profile.id == id)
+            .cloned();
+
+        Ok(option_profile)
+    }
+
+    async fn active_profile(&mut self, user_id: i32) -> Result<Option<Profile>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let option_profile = database.profiles
+            .iter()
+            .find(|profile| profile.user_id == user_id && profile.is_active)
+            .cloned();
+
+        Ok(option_profile)
+    }
+
+    async fn active_profile_by_discord_id(&mut self, discord_user_id: u64) -> Result<Option<Profile>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let option_profile = match database.user_id_by_discord_id(discord_user_id) {
+            Some(user_id) => database.profiles
+                .iter()
+                .find(|profile| profile.user_id == user_id && profile.is_active)
+                .cloned(),
+            None => None,
+        };
+
+        Ok(option_profile)
+    }
+
+    async fn profiles_by_user_id(&mut self, user_id: i32) -> Result<Vec<Profile>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        Ok(database.profile_history(user_id))
+    }
+
+    async fn profiles_by_discord_id(&mut self, discord_user_id: u64) -> Result<Vec<Profile>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let results = match database.user_id_by_discord_id(discord_user_id) {
+            Some(user_id) => database.profile_history(user_id),
+            None => Vec::new(),
+        };
+
+        Ok(results)
+    }
+
+    async fn profiles_by_user_ids(&mut self, user_ids: &[i32]) -> Result<Vec<Profile>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let mut results: Vec<_> = database.profiles
+            .iter()
+            .filter(|profile| user_ids.contains(&profile.user_id))
+            .cloned()
+            .collect();
+
+        results.sort_by(|a, b| a.user_id.cmp(&b.user_id).then(b.created_at.cmp(&a.created_at)).then(b.id.cmp(&a.id)));
+
+        Ok(results)
+    }
+
+    async fn set_active_profile(&mut self, user_id: i32, profile_id: i32) -> Result<bool, RepositoryError<Self::BackendError>> {
+        let mut database = self.database();
+
+        let profile = match database.profiles.iter_mut().find(|profile| profile.id == profile_id) {
+            Some(profile) => profile,
+            None => return Ok(false),
+        };
+
+        profile.is_active = true;
+
+        database.profiles
+            .iter_mut()
+            .filter(|profile| profile.user_id == user_id && profile.id != profile_id)
+            .for_each(|profile| profile.is_active = false);
+
+        Ok(true)
+    }
+
+    async fn insert_profile_version(&mut self, new_profile: NewProfile, created_at: DateTime<Utc>, is_active: bool) -> Result<Profile, RepositoryError<Self::BackendError>> {
+        let mut database = self.database();
+
+        let mut profile = database.new_profile(new_profile)?;
+        profile.created_at = created_at;
+        profile.is_active = is_active;
+
+        database.profiles.push(profile.clone());
+
+        Ok(profile)
+    }
+
+    async fn profile_count(&mut self) -> Result<u64, RepositoryError<Self::BackendError>> {
+        Ok(self.database().profiles.len() as u64)
+    }
+
+    async fn active_profiles_with_code(&mut self, game: FriendCodeGame) -> Result<Vec<(u64, Profile)>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let results = database.users
+            .iter()
+            .filter_map(|user| {
+                database.profiles
+                    .iter()
+                    .find(|profile| profile.user_id == user.id && profile.is_active && profile.friend_code(game).is_some())
+                    .map(|profile| (user.discord_user_id, profile.clone()))
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    async fn query_profiles(&mut self, filter: &ProfileFilter, limit: u32, offset: u64) -> Result<ProfilePage, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let mut matches: Vec<_> = database.users
+            .iter()
+            .filter(|user| filter.discord_user_ids.as_ref().is_none_or(|discord_user_ids| discord_user_ids.contains(&user.discord_user_id)))
+            .filter_map(|user| {
+                database.profiles
+                    .iter()
+                    .find(|profile| profile.user_id == user.id && filter.matches(profile))
+                    .map(|profile| (user.id, user.discord_user_id, profile))
+            })
+            .collect();
+
+        matches.sort_by_key(|(user_id, _, _)| *user_id);
+
+        let total = matches.len() as u64;
+        let profiles = matches
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|(_, discord_user_id, profile)| (discord_user_id, profile.clone()))
+            .collect();
+
+        Ok(ProfilePage {
+            profiles,
+            total,
+        })
+    }
+
+    async fn active_profile_values(&mut self, field: ProfileTextField, prefix: &str, limit: u32) -> Result<Vec<String>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+        let prefix = prefix.to_lowercase();
+
+        let mut results: Vec<_> = database.profiles
+            .iter()
+            .filter(|profile| profile.is_active)
+            .filter_map(|profile| profile.text_field(field))
+            .filter(|value| value.to_lowercase().starts_with(&prefix))
+            .map(str::to_string)
+            .collect();
+
+        results.sort();
+        results.dedup();
+        results.truncate(limit as usize);
+
+        Ok(results)
+    }
+}
+
+impl MemoryDatabase {
+    /// Assigns an id to a new active profile without storing it.
+    fn new_profile(&mut self, new_profile: NewProfile) -> Result<Profile, RepositoryError<BackendError>> {
+        if !self.users.iter().any(|user| user.id == new_profile.user_id) {
+            return Err(constraint_violation(DatabaseErrorKind::ForeignKeyViolation, "profiles.user_id does not reference an existing user"));
+        }
+
+        self.last_profile_id += 1;
+
+        Ok(Profile {
+            id: self.last_profile_id,
+            user_id: new_profile.user_id,
+
+            thumbnail_url: new_profile.thumbnail_url,
+            image_url: new_profile.image_url,
+
+            trainer_class: new_profile.trainer_class,
+            nature: new_profile.nature,
+            partner_pokemon: new_profile.partner_pokemon,
+            starting_region: new_profile.starting_region,
+            favourite_food: new_profile.favourite_food,
+            likes: new_profile.likes,
+            quotes: new_profile.quotes,
+
+            pokemon_go_code: new_profile.pokemon_go_code,
+            pokemon_pocket_code: new_profile.pokemon_pocket_code,
+            switch_code: new_profile.switch_code,
+
+            created_at: Utc::now(),
+            is_active: true,
+        })
+    }
+
+    fn user_id_by_discord_id(&self, discord_user_id: u64) -> Option<i32> {
+        self.users
+            .iter()
+            .find(|user| user.discord_user_id == discord_user_id)
+            .map(|user| user.id)
+    }
+
+    /// Returns every profile version belonging to a user, newest first.
+    fn profile_history(&self, user_id: i32) -> Vec<Profile> {
+        let mut results: Vec<_> = self.profiles
+            .iter()
+            .filter(|profile| profile.user_id == user_id)
+            .cloned()
+            .collect();
+
+        results.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+
+        results
+    }
+}
@@ -0,0 +1,121 @@
+use cipher_core::repository::staff_role_repository::StaffRole;
+use cipher_core::repository::staff_role_repository::StaffRoleRepository;
+use cipher_core::repository::RepositoryError;
+
+use crate::BackendError;
+
+use super::MemoryRepository;
+use super::MemoryStaffRole;
+
+#[async_trait::async_trait]
+impl StaffRoleRepository for MemoryRepository<'_> {
+    type BackendError = BackendError;
+
+    async fn is_staff_role(&mut self, guild_id: u64, id: u64) -> Result<bool, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let result = database.staff_roles
+            .iter()
+            .any(|staff_role| staff_role.applies_in(guild_id) && staff_role.discord_role_id == id);
+
+        Ok(result)
+    }
+
+    async fn staff_roles(&mut self, guild_id: u64) -> Result<Vec<u64>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let mut results = Vec::new();
+        for staff_role in database.staff_roles.iter().filter(|staff_role| staff_role.applies_in(guild_id)) {
+            if !results.contains(&staff_role.discord_role_id) {
+                results.push(staff_role.discord_role_id);
+            }
+        }
+
+        Ok(results)
+    }
+
+    async fn staff_roles_contains(&mut self, guild_id: u64, ids: &[u64]) -> Result<bool, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let result = database.staff_roles
+            .iter()
+            .any(|staff_role| staff_role.applies_in(guild_id) && ids.contains(&staff_role.discord_role_id));
+
+        Ok(result)
+    }
+
+    async fn set_staff_role(&mut self, guild_id: u64, id: u64) -> Result<(), RepositoryError<Self::BackendError>> {
+        let mut database = self.database();
+
+        let is_duplicate = database.staff_roles
+            .iter()
+            .any(|staff_role| staff_role.discord_guild_id == Some(guild_id) && staff_role.discord_role_id == id);
+
+        if is_duplicate {
+            return Ok(());
+        }
+
+        database.last_staff_role_id += 1;
+
+        let staff_role = MemoryStaffRole {
+            id: database.last_staff_role_id,
+            discord_role_id: id,
+            discord_guild_id: Some(guild_id),
+        };
+
+        database.staff_roles.push(staff_role);
+
+        Ok(())
+    }
+
+    async fn unset_staff_role(&mut self, guild_id: u64, id: u64) -> Result<(), RepositoryError<Self::BackendError>> {
+        let mut database = self.database();
+
+        database.staff_roles.retain(|staff_role| !(staff_role.discord_guild_id == Some(guild_id) && staff_role.discord_role_id == id));
+
+        Ok(())
+    }
+
+    async fn all_staff_roles(&mut self) -> Result<Vec<StaffRole>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let results = database.staff_roles
+            .iter()
+            .map(|staff_role| StaffRole {
+                discord_role_id: staff_role.discord_role_id,
+                discord_guild_id: staff_role.discord_guild_id,
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    async fn insert_staff_role(&mut self, staff_role: StaffRole) -> Result<(), RepositoryError<Self::BackendError>> {
+        let mut database = self.database();
+
+        let is_duplicate = database.staff_roles
+            .iter()
+            .any(|other| other.discord_guild_id == staff_role.discord_guild_id && other.discord_role_id == staff_role.discord_role_id);
+
+        // Rows without a guild are never considered duplicates, matching the unique index of the SQL dialects.
+        if is_duplicate && staff_role.discord_guild_id.is_some() {
+            return Ok(());
+        }
+
+        database.last_staff_role_id += 1;
+
+        let staff_role = MemoryStaffRole {
+            id: database.last_staff_role_id,
+            discord_role_id: staff_role.discord_role_id,
+            discord_guild_id: staff_role.discord_guild_id,
+        };
+
+        database.staff_roles.push(staff_role);
+
+        Ok(())
+    }
+
+    async fn staff_role_count(&mut self) -> Result<u64, RepositoryError<Self::BackendError>> {
+        Ok(self.database().staff_roles.len() as u64)
+    }
+}
@@ -0,0 +1,134 @@
+use cipher_core::repository::user_repository::NewUser;
+use cipher_core::repository::user_repository::User;
+use cipher_core::repository::user_repository::UserRepository;
+use cipher_core::repository::RepositoryError;
+use diesel::result::DatabaseErrorKind;
+
+use crate::BackendError;
+
+use super::constraint_violation;
+use super::MemoryRepository;
+use super::MemoryUser;
+
+#[async_trait::async_trait]
+impl UserRepository for MemoryRepository<'_> {
+    type BackendError = BackendError;
+
+    async fn user(&mut self, id: i32) -> Result<Option<User>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let option_user = database.users
+            .iter()
+            .find(|user| user.id == id)
+            .map(User::from);
+
+        Ok(option_user)
+    }
+
+    async fn user_by_discord_user_id(&mut self, id: u64) -> Result<Option<User>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let option_user = database.users
+            .iter()
+            .find(|user| user.discord_user_id == id)
+            .map(User::from);
+
+        Ok(option_user)
+    }
+
+    async fn insert_user(&mut self, new_user: NewUser) -> Result<User, RepositoryError<Self::BackendError>> {
+        let mut database = self.database();
+
+        if database.users.iter().any(|user| user.discord_user_id == new_user.discord_user_id) {
+            return Err(constraint_violation(DatabaseErrorKind::UniqueViolation, "users.discord_user_id must be unique"));
+        }
+
+        database.last_user_id += 1;
+
+        let user = MemoryUser {
+            id: database.last_user_id,
+            discord_user_id: new_user.discord_user_id,
+        };
+
+        let result = User::from(&user);
+        database.users.push(user);
+
+        Ok(result)
+    }
+
+    async fn update_user(&mut self, user: User) -> Result<Option<User>, RepositoryError<Self::BackendError>> {
+        let mut database = self.database();
+
+        let is_duplicate = database.users
+            .iter()
+            .any(|other| other.id != user.id && other.discord_user_id == user.discord_user_id);
+
+        let previous = match database.users.iter_mut().find(|previous| previous.id == user.id) {
+            Some(previous) => previous,
+            None => return Ok(None),
+        };
+
+        if is_duplicate {
+            return Err(constraint_violation(DatabaseErrorKind::UniqueViolation, "users.discord_user_id must be unique"));
+        }
+
+        let result = User::from(&*previous);
+        previous.discord_user_id = user.discord_user_id;
+
+        Ok(Some(result))
+    }
+
+    async fn delete_user(&mut self, id: i32) -> Result<bool, RepositoryError<Self::BackendError>> {
+        let mut database = self.database();
+
+        let user_count = database.users.len();
+        database.users.retain(|user| user.id != id);
+
+        if database.users.len() == user_count {
+            return Ok(false);
+        }
+
+        database.profiles.retain(|profile| profile.user_id != id);
+
+        Ok(true)
+    }
+
+    async fn users(&mut self) -> Result<Vec<User>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let results = database.users
+            .iter()
+            .map(User::from)
+            .collect();
+
+        Ok(results)
+    }
+
+    async fn users_after(&mut self, after_id: i32, limit: u32) -> Result<Vec<User>, RepositoryError<Self::BackendError>> {
+        let database = self.database();
+
+        let mut results: Vec<_> = database.users
+            .iter()
+            .filter(|user| user.id > after_id)
+            .map(User::from)
+            .collect();
+
+        results.sort_by_key(|user| user.id);
+        results.truncate(limit as usize);
+
+        Ok(results)
+    }
+
+    async fn user_count(&mut self) -> Result<u64, RepositoryError<Self::BackendError>> {
+        Ok(self.database().users.len() as u64)
+    }
+}
+
+impl From<&MemoryUser> for User {
+    fn from(value: &MemoryUser) -> Self {
+        Self {
+            id: value.id,
+            discord_user_id: value.discord_user_id,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use diesel::backend::Backend;
+use diesel::migration::MigrationSource;
+use diesel_migrations::MigrationError;
+use diesel_migrations::MigrationHarness;
+
+use crate::BackendError;
+
+/// The state of a single embedded migration in a database.
+#[derive(Clone, Debug)]
+pub struct MigrationStatus {
+    /// The name of the migration, prefixed by its version.
+    pub name: String,
+    /// Whether the migration has been applied to the database.
+    pub is_applied: bool,
+}
+
+/// Lists every migration in `source` in ascending version order.
+pub(crate) fn migration_status<DB, H, S>(harness: &mut H, source: &S) -> Result<Vec<MigrationStatus>, BackendError>
+where
+    DB: Backend,
+    H: MigrationHarness<DB>,
+    S: MigrationSource<DB>,
+{
+    let applied_versions = harness.applied_migrations().map_err(BackendError::DieselMigrationError)?;
+    let mut migrations = source.migrations().map_err(BackendError::DieselMigrationError)?;
+
+    migrations.sort_unstable_by(|a, b| a.name().version().cmp(&b.name().version()));
+
+    let results = migrations.iter()
+        .map(|migration| MigrationStatus {
+            name: migration.name().to_string(),
+            is_applied: applied_versions.contains(&migration.name().version()),
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// Applies up to `count` pending migrations in ascending version order, or all of them if `count` is `None`.
+///
+/// Returns the names of the applied migrations.
+pub(crate) fn run_next_migrations<DB, H, S>(harness: &mut H, source: &S, count: Option<usize>) -> Result<Vec<String>, BackendError>
+where
+    DB: Backend,
+    H: MigrationHarness<DB>,
+    S: MigrationSource<DB>,
+{
+    let applied_versions = harness.applied_migrations().map_err(BackendError::DieselMigrationError)?;
+    let mut migrations = source.migrations().map_err(BackendError::DieselMigrationError)?;
+
+    migrations.retain(|migration| !applied_versions.contains(&migration.name().version()));
+    migrations.sort_unstable_by(|a, b| a.name().version().cmp(&b.name().version()));
+
+    let mut results = Vec::new();
+    for migration in migrations.iter().take(count.unwrap_or(usize::MAX)) {
+        harness.run_migration(migration.as_ref()).map_err(BackendError::DieselMigrationError)?;
+        results.push(migration.name().to_string());
+    }
+
+    Ok(results)
+}
+
+/// Reverts up to `count` applied migrations, starting with the most recent version.
+///
+/// Returns the names of the reverted migrations.
+pub(crate) fn revert_last_migrations<DB, H, S>(harness: &mut H, source: &S, count: usize) -> Result<Vec<String>, BackendError>
+where
+    DB: Backend,
+    H: MigrationHarness<DB>,
+    S: MigrationSource<DB>,
+{
+    // Applied versions are returned in descending order.
+    let applied_versions = harness.applied_migrations().map_err(BackendError::DieselMigrationError)?;
+    let migrations = source.migrations().map_err(BackendError::DieselMigrationError)?;
+
+    let mut results = Vec::new();
+    for version in applied_versions.into_iter().take(count) {
+        let migration = migrations.iter()
+            .find(|migration| migration.name().version() == version)
+            .ok_or_else(|| BackendError::DieselMigrationError(Box::new(MigrationError::UnknownMigrationVersion(version))))?;
+
+        harness.revert_migration(migration.as_ref()).map_err(BackendError::DieselMigrationError)?;
+        results.push(migration.name().to_string());
+    }
+
+    Ok(results)
+}
@@ -8,6 +8,7 @@ use diesel_migrations::MigrationHarness;
 use repository::MysqlRepositoryProvider;
 
 use crate::BackendError;
+use crate::MigrationStatus;
 
 pub mod repository;
 mod schema;
@@ -22,6 +23,21 @@ pub fn run_pending_migrations(database_url: &str) -> Result<(), BackendError> {
     Ok(())
 }
 
+pub fn migration_status(database_url: &str) -> Result<Vec<MigrationStatus>, BackendError> {
+    let mut connection = MysqlConnection::establish(database_url)?;
+    crate::migration::migration_status(&mut connection, &MIGRATIONS)
+}
+
+pub fn run_next_migrations(database_url: &str, count: Option<usize>) -> Result<Vec<String>, BackendError> {
+    let mut connection = MysqlConnection::establish(database_url)?;
+    crate::migration::run_next_migrations(&mut connection, &MIGRATIONS, count)
+}
+
+pub fn revert_last_migrations(database_url: &str, count: usize) -> Result<Vec<String>, BackendError> {
+    let mut connection = MysqlConnection::establish(database_url)?;
+    crate::migration::revert_last_migrations(&mut connection, &MIGRATIONS, count)
+}
+
 pub async fn repository_provider(database_url: &str) -> Result<MysqlRepositoryProvider, BackendError> {
     let config = AsyncDieselConnectionManager::new(database_url);
     let pool = Pool::builder().build(config).await?;
@@ -1,6 +1,8 @@
 use diesel_async::pooled_connection::bb8::Pool;
 use diesel_async::pooled_connection::bb8::PooledConnection;
+use diesel_async::AnsiTransactionManager;
 use diesel_async::AsyncMysqlConnection;
+use diesel_async::TransactionManager;
 use cipher_core::repository::Repository;
 use cipher_core::repository::RepositoryError;
 use cipher_core::repository::RepositoryProvider;
@@ -21,8 +23,27 @@ impl<'a> MysqlRepository<'a> {
     }
 }
 
+#[async_trait::async_trait]
 impl Repository for MysqlRepository<'_> {
     type BackendError = BackendError;
+
+    async fn begin_transaction(&mut self) -> Result<(), RepositoryError<BackendError>> {
+        AnsiTransactionManager::begin_transaction(&mut *self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))
+    }
+
+    async fn commit_transaction(&mut self) -> Result<(), RepositoryError<BackendError>> {
+        AnsiTransactionManager::commit_transaction(&mut *self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))
+    }
+
+    async fn rollback_transaction(&mut self) -> Result<(), RepositoryError<BackendError>> {
+        AnsiTransactionManager::rollback_transaction(&mut *self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))
+    }
 }
 
 pub struct MysqlRepositoryProvider {
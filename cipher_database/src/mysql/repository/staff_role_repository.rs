@@ -2,6 +2,7 @@ use diesel::prelude::*;
 use diesel_async::scoped_futures::ScopedFutureExt;
 use diesel_async::AsyncConnection;
 use diesel_async::RunQueryDsl;
+use cipher_core::repository::staff_role_repository::StaffRole;
 use cipher_core::repository::staff_role_repository::StaffRoleRepository;
 use cipher_core::repository::RepositoryError;
 
@@ -14,10 +15,12 @@ use super::MysqlRepository;
 impl StaffRoleRepository for MysqlRepository<'_> {
     type BackendError = BackendError;
 
-    async fn is_staff_role(&mut self, id: u64) -> Result<bool, RepositoryError<Self::BackendError>> {
+    async fn is_staff_role(&mut self, guild_id: u64, id: u64) -> Result<bool, RepositoryError<Self::BackendError>> {
+        let model_guild_id = guild_id as i64;
         let model_id = id as i64;
 
         staff_roles::dsl::staff_roles
+            .filter(staff_roles::dsl::discord_guild_id.eq(model_guild_id).or(staff_roles::dsl::discord_guild_id.is_null()))
             .filter(staff_roles::dsl::discord_role_id.eq(model_id))
             .first::<ModelStaffRole>(&mut self.conn)
             .await
@@ -26,24 +29,30 @@ impl StaffRoleRepository for MysqlRepository<'_> {
             .map_err(|err| RepositoryError(BackendError::from(err)))
     }
 
-    async fn staff_roles(&mut self) -> Result<Vec<u64>, RepositoryError<Self::BackendError>> {
+    async fn staff_roles(&mut self, guild_id: u64) -> Result<Vec<u64>, RepositoryError<Self::BackendError>> {
+        let model_guild_id = guild_id as i64;
+
         let model_results = staff_roles::dsl::staff_roles
-            .select(ModelStaffRole::as_select())
-            .load(&mut self.conn)
+            .filter(staff_roles::dsl::discord_guild_id.eq(model_guild_id).or(staff_roles::dsl::discord_guild_id.is_null()))
+            .select(staff_roles::dsl::discord_role_id)
+            .distinct()
+            .load::<i64>(&mut self.conn)
             .await
             .map_err(|err| RepositoryError(BackendError::from(err)))?;
 
         let results = model_results.into_iter()
-            .map(|r| r.discord_role_id as u64)
+            .map(|discord_role_id| discord_role_id as u64)
             .collect();
 
         Ok(results)
     }
 
-    async fn staff_roles_contains(&mut self, ids: &[u64]) -> Result<bool, RepositoryError<Self::BackendError>> {
-        let model_ids: Vec<_> = ids.into_iter().map(|&id| id as i64).collect();
+    async fn staff_roles_contains(&mut self, guild_id: u64, ids: &[u64]) -> Result<bool, RepositoryError<Self::BackendError>> {
+        let model_guild_id = guild_id as i64;
+        let model_ids: Vec<_> = ids.iter().map(|&id| id as i64).collect();
 
         staff_roles::dsl::staff_roles
+            .filter(staff_roles::dsl::discord_guild_id.eq(model_guild_id).or(staff_roles::dsl::discord_guild_id.is_null()))
             .filter(staff_roles::dsl::discord_role_id.eq_any(&model_ids))
             .first::<ModelStaffRole>(&mut self.conn)
             .await
@@ -52,9 +61,10 @@ impl StaffRoleRepository for MysqlRepository<'_> {
             .map_err(|err| RepositoryError(BackendError::from(err)))
     }
 
-    async fn set_staff_role(&mut self, id: u64) -> Result<(), RepositoryError<Self::BackendError>> {
+    async fn set_staff_role(&mut self, guild_id: u64, id: u64) -> Result<(), RepositoryError<Self::BackendError>> {
         let new_staff_role = ModelNewStaffRole {
             discord_role_id: id as i64,
+            discord_guild_id: Some(guild_id as i64),
         };
 
         self.conn
@@ -71,12 +81,15 @@ impl StaffRoleRepository for MysqlRepository<'_> {
         Ok(())
     }
 
-    async fn unset_staff_role(&mut self, id: u64) -> Result<(), RepositoryError<Self::BackendError>> {
+    async fn unset_staff_role(&mut self, guild_id: u64, id: u64) -> Result<(), RepositoryError<Self::BackendError>> {
+        let model_guild_id = guild_id as i64;
         let model_id = id as i64;
 
         self.conn
             .transaction::<_, diesel::result::Error, _>(move |conn| async move {
-                diesel::delete(staff_roles::dsl::staff_roles.filter(staff_roles::dsl::discord_role_id.eq(model_id)))
+                diesel::delete(staff_roles::dsl::staff_roles)
+                    .filter(staff_roles::dsl::discord_guild_id.eq(model_guild_id))
+                    .filter(staff_roles::dsl::discord_role_id.eq(model_id))
                     .execute(conn)
                     .await
             }.scope_boxed())
@@ -85,6 +98,49 @@ impl StaffRoleRepository for MysqlRepository<'_> {
 
         Ok(())
     }
+
+    async fn all_staff_roles(&mut self) -> Result<Vec<StaffRole>, RepositoryError<Self::BackendError>> {
+        let results = staff_roles::dsl::staff_roles
+            .order(staff_roles::dsl::id.asc())
+            .select(ModelStaffRole::as_select())
+            .load(&mut self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))?
+            .into_iter()
+            .map(StaffRole::from)
+            .collect();
+
+        Ok(results)
+    }
+
+    async fn insert_staff_role(&mut self, staff_role: StaffRole) -> Result<(), RepositoryError<Self::BackendError>> {
+        let new_staff_role = ModelNewStaffRole {
+            discord_role_id: staff_role.discord_role_id as i64,
+            discord_guild_id: staff_role.discord_guild_id.map(|discord_guild_id| discord_guild_id as i64),
+        };
+
+        self.conn
+            .transaction::<_, diesel::result::Error, _>(|conn| async move {
+                diesel::insert_into(staff_roles::table)
+                    .values(&new_staff_role)
+                    .on_conflict_do_nothing()
+                    .execute(conn)
+                    .await
+            }.scope_boxed())
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))?;
+
+        Ok(())
+    }
+
+    async fn staff_role_count(&mut self) -> Result<u64, RepositoryError<Self::BackendError>> {
+        staff_roles::table
+            .count()
+            .get_result::<i64>(&mut self.conn)
+            .await
+            .map(|count| count as u64)
+            .map_err(|err| RepositoryError(BackendError::from(err)))
+    }
 }
 
 #[derive(Queryable, Selectable, AsChangeset)]
@@ -95,6 +151,16 @@ pub struct ModelStaffRole {
     #[allow(unused)]
     id: i32,
     discord_role_id: i64,
+    discord_guild_id: Option<i64>,
+}
+
+impl From<ModelStaffRole> for StaffRole {
+    fn from(value: ModelStaffRole) -> Self {
+        Self {
+            discord_role_id: value.discord_role_id as u64,
+            discord_guild_id: value.discord_guild_id.map(|discord_guild_id| discord_guild_id as u64),
+        }
+    }
 }
 
 #[derive(Insertable)]
@@ -103,4 +169,5 @@ pub struct ModelStaffRole {
 #[diesel(check_for_backend(diesel::mysql::Mysql))]
 pub struct ModelNewStaffRole {
     discord_role_id: i64,
+    discord_guild_id: Option<i64>,
 }
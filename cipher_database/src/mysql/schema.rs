@@ -28,6 +28,7 @@ diesel::table! {
     staff_roles (id) {
         id -> Integer,
         discord_role_id -> Bigint,
+        discord_guild_id -> Nullable<Bigint>,
     }
 }
 
@@ -1,17 +1,27 @@
 use chrono::DateTime;
 use chrono::NaiveDateTime;
 use chrono::Utc;
+use cipher_core::repository::profile_repository::FriendCodeGame;
 use cipher_core::repository::profile_repository::NewProfile;
 use diesel_async::scoped_futures::ScopedFutureExt;
 use diesel_async::AsyncConnection;
 use diesel_async::RunQueryDsl;
 use cipher_core::repository::profile_repository::Profile;
+use cipher_core::repository::profile_repository::ProfileFilter;
+use cipher_core::repository::profile_repository::ProfilePage;
+use cipher_core::repository::profile_repository::ProfileTextField;
 use cipher_core::repository::profile_repository::ProfileRepository;
 use cipher_core::repository::RepositoryError;
+use diesel::dsl::InnerJoin;
+use diesel::dsl::IntoBoxed;
 use diesel::prelude::*;
+use diesel::sql_types::Nullable;
+use diesel::sql_types::Text;
 
 use crate::postgres::schema::profiles;
 use crate::postgres::schema::users;
+use crate::sql_function::escape_like_pattern;
+use crate::sql_function::lower;
 use crate::BackendError;
 
 use super::PostgresRepository;
@@ -106,6 +116,20 @@ impl ProfileRepository for PostgresRepository<'_> {
         Ok(results)
     }
 
+    async fn profiles_by_user_ids(&mut self, user_ids: &[i32]) -> Result<Vec<Profile>, RepositoryError<Self::BackendError>> {
+        let results: Vec<_> = profiles::dsl::profiles
+            .filter(profiles::user_id.eq_any(user_ids))
+            .order((profiles::user_id.asc(), profiles::created_at.desc()))
+            .get_results::<ModelProfile>(&mut self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))?
+            .into_iter()
+            .map(Profile::from)
+            .collect();
+
+        Ok(results)
+    }
+
     async fn set_active_profile(&mut self, user_id: i32, profile_id: i32) -> Result<bool, RepositoryError<Self::BackendError>> {
         self.conn
             .transaction::<_, diesel::result::Error, _>(move |conn| async move {
@@ -131,6 +155,158 @@ impl ProfileRepository for PostgresRepository<'_> {
             .await
             .map_err(|err| RepositoryError(BackendError::from(err)))
     }
+
+    async fn insert_profile_version(&mut self, new_profile: NewProfile, created_at: DateTime<Utc>, is_active: bool) -> Result<Profile, RepositoryError<Self::BackendError>> {
+        let model_new_profile = ModelNewProfile {
+            created_at: created_at.naive_utc(),
+            is_active,
+            ..ModelNewProfile::from(new_profile)
+        };
+
+        self.conn
+            .transaction::<_, diesel::result::Error, _>(|conn| async move {
+                diesel::insert_into(profiles::table)
+                    .values(&model_new_profile)
+                    .returning(ModelProfile::as_returning())
+                    .get_result(conn)
+                    .await
+            }.scope_boxed())
+            .await
+            .map(Profile::from)
+            .map_err(|err| RepositoryError(BackendError::from(err)))
+    }
+
+    async fn profile_count(&mut self) -> Result<u64, RepositoryError<Self::BackendError>> {
+        profiles::table
+            .count()
+            .get_result::<i64>(&mut self.conn)
+            .await
+            .map(|count| count as u64)
+            .map_err(|err| RepositoryError(BackendError::from(err)))
+    }
+
+    async fn active_profiles_with_code(&mut self, game: FriendCodeGame) -> Result<Vec<(u64, Profile)>, RepositoryError<Self::BackendError>> {
+        let query = profiles::table
+            .inner_join(users::table)
+            .filter(profiles::is_active.eq(true))
+            .select((users::discord_user_id, ModelProfile::as_select()))
+            .into_boxed();
+
+        let query = match game {
+            FriendCodeGame::PokemonGo => query.filter(profiles::pokemon_go_code.is_not_null()),
+            FriendCodeGame::PokemonPocket => query.filter(profiles::pokemon_pocket_code.is_not_null()),
+            FriendCodeGame::Switch => query.filter(profiles::switch_code.is_not_null()),
+        };
+
+        let results = query
+            .order(users::id.asc())
+            .load::<(i64, ModelProfile)>(&mut self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))?
+            .into_iter()
+            .map(|(discord_user_id, profile)| (discord_user_id as u64, Profile::from(profile)))
+            .collect();
+
+        Ok(results)
+    }
+
+    async fn query_profiles(&mut self, filter: &ProfileFilter, limit: u32, offset: u64) -> Result<ProfilePage, RepositoryError<Self::BackendError>> {
+        let total = filtered_active_profiles(filter)
+            .count()
+            .get_result::<i64>(&mut self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))?;
+
+        let profiles = filtered_active_profiles(filter)
+            .select((users::discord_user_id, ModelProfile::as_select()))
+            .order(users::id.asc())
+            .limit(limit as i64)
+            .offset(offset as i64)
+            .load::<(i64, ModelProfile)>(&mut self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))?
+            .into_iter()
+            .map(|(discord_user_id, profile)| (discord_user_id as u64, Profile::from(profile)))
+            .collect();
+
+        Ok(ProfilePage {
+            profiles,
+            total: total as u64,
+        })
+    }
+
+    async fn active_profile_values(&mut self, field: ProfileTextField, prefix: &str, limit: u32) -> Result<Vec<String>, RepositoryError<Self::BackendError>> {
+        let pattern = format!("{}%", escape_like_pattern(&prefix.to_lowercase()));
+
+        let results = profiles::table
+            .into_boxed()
+            .filter(profiles::is_active.eq(true))
+            .filter(lower(text_field_column(field)).like(pattern).escape('\\'))
+            .select(text_field_column(field))
+            .distinct()
+            .order(text_field_column(field).asc())
+            .limit(limit as i64)
+            .load::<Option<String>>(&mut self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        Ok(results)
+    }
+}
+
+/// Returns the column storing a text field.
+fn text_field_column(field: ProfileTextField) -> Box<dyn BoxableExpression<profiles::table, diesel::pg::Pg, SqlType = Nullable<Text>>> {
+    match field {
+        ProfileTextField::TrainerClass => Box::new(profiles::trainer_class),
+        ProfileTextField::Nature => Box::new(profiles::nature),
+        ProfileTextField::PartnerPokemon => Box::new(profiles::partner_pokemon),
+        ProfileTextField::StartingRegion => Box::new(profiles::starting_region),
+    }
+}
+
+/// Builds a query for the active profiles that satisfy `filter`, joined with their users.
+fn filtered_active_profiles(filter: &ProfileFilter) -> IntoBoxed<'static, InnerJoin<profiles::table, users::table>, diesel::pg::Pg> {
+    let mut query = profiles::table
+        .inner_join(users::table)
+        .into_boxed()
+        .filter(profiles::is_active.eq(true));
+
+    if let Some(partner_pokemon) = &filter.partner_pokemon {
+        query = query.filter(lower(profiles::partner_pokemon).eq(partner_pokemon.to_lowercase()));
+    }
+
+    if let Some(starting_region) = &filter.starting_region {
+        query = query.filter(lower(profiles::starting_region).eq(starting_region.to_lowercase()));
+    }
+
+    if let Some(trainer_class) = &filter.trainer_class {
+        query = query.filter(lower(profiles::trainer_class).eq(trainer_class.to_lowercase()));
+    }
+
+    if let Some(nature) = &filter.nature {
+        query = query.filter(lower(profiles::nature).eq(nature.to_lowercase()));
+    }
+
+    query = match filter.has_code_for_game {
+        Some(FriendCodeGame::PokemonGo) => query.filter(profiles::pokemon_go_code.is_not_null()),
+        Some(FriendCodeGame::PokemonPocket) => query.filter(profiles::pokemon_pocket_code.is_not_null()),
+        Some(FriendCodeGame::Switch) => query.filter(profiles::switch_code.is_not_null()),
+        None => query,
+    };
+
+    if let Some(created_after) = filter.created_after {
+        query = query.filter(profiles::created_at.gt(created_after.naive_utc()));
+    }
+
+    if let Some(discord_user_ids) = &filter.discord_user_ids {
+        let model_discord_user_ids: Vec<_> = discord_user_ids.iter().map(|discord_user_id| *discord_user_id as i64).collect();
+        query = query.filter(users::discord_user_id.eq_any(model_discord_user_ids));
+    }
+
+    query
 }
 
 #[derive(Queryable, Selectable, AsChangeset)]
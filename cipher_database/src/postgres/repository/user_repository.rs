@@ -7,6 +7,7 @@ use cipher_core::repository::user_repository::User;
 use cipher_core::repository::user_repository::UserRepository;
 use cipher_core::repository::RepositoryError;
 
+use crate::postgres::schema::profiles;
 use crate::postgres::schema::users;
 use crate::BackendError;
 
@@ -81,6 +82,62 @@ impl UserRepository for PostgresRepository<'_> {
             .map(|option| option.map(User::from))
             .map_err(|err| RepositoryError(BackendError::from(err)))
     }
+
+    async fn delete_user(&mut self, id: i32) -> Result<bool, RepositoryError<Self::BackendError>> {
+        self.conn
+            .transaction::<_, diesel::result::Error, _>(|conn| async move {
+                diesel::delete(profiles::dsl::profiles.filter(profiles::dsl::user_id.eq(id)))
+                    .execute(conn)
+                    .await?;
+
+                let deleted_rows = diesel::delete(users::dsl::users.find(id))
+                    .execute(conn)
+                    .await?;
+
+                Ok(deleted_rows > 0)
+            }.scope_boxed())
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))
+    }
+
+    async fn users(&mut self) -> Result<Vec<User>, RepositoryError<Self::BackendError>> {
+        let results = users::dsl::users
+            .order(users::dsl::id.asc())
+            .select(ModelUser::as_select())
+            .load(&mut self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))?
+            .into_iter()
+            .map(User::from)
+            .collect();
+
+        Ok(results)
+    }
+
+    async fn users_after(&mut self, after_id: i32, limit: u32) -> Result<Vec<User>, RepositoryError<Self::BackendError>> {
+        let results = users::dsl::users
+            .filter(users::dsl::id.gt(after_id))
+            .order(users::dsl::id.asc())
+            .limit(limit as i64)
+            .select(ModelUser::as_select())
+            .load(&mut self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))?
+            .into_iter()
+            .map(User::from)
+            .collect();
+
+        Ok(results)
+    }
+
+    async fn user_count(&mut self) -> Result<u64, RepositoryError<Self::BackendError>> {
+        users::table
+            .count()
+            .get_result::<i64>(&mut self.conn)
+            .await
+            .map(|count| count as u64)
+            .map_err(|err| RepositoryError(BackendError::from(err)))
+    }
 }
 
 #[derive(Queryable, Selectable, AsChangeset)]
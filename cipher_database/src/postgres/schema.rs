@@ -28,6 +28,7 @@ diesel::table! {
     staff_roles (id) {
         id -> Int4,
         discord_role_id -> Int8,
+        discord_guild_id -> Nullable<Int8>,
     }
 }
 
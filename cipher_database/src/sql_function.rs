@@ -0,0 +1,30 @@
+use diesel::sql_types::Nullable;
+use diesel::sql_types::Text;
+
+diesel::define_sql_function! {
+    /// Converts text to lower case, supported by every SQL dialect.
+    ///
+    /// The built-in SQLite implementation only converts ASCII characters, so SQLite
+    /// connections replace it with [`unicode_lower`] when they are established.
+    fn lower(x: Nullable<Text>) -> Nullable<Text>;
+}
+
+/// Converts text to lower case the same way the memory backend does.
+#[cfg(feature = "sqlite")]
+pub(crate) fn unicode_lower(value: Option<String>) -> Option<String> {
+    value.map(|value| value.to_lowercase())
+}
+
+/// Escapes the wildcard characters of a `LIKE` pattern using `\` as the escape character.
+pub(crate) fn escape_like_pattern(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        if matches!(character, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+
+    escaped
+}
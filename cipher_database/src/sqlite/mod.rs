@@ -1,13 +1,19 @@
 use diesel::Connection;
+use diesel::ConnectionError;
+use diesel::ConnectionResult;
 use diesel::SqliteConnection;
 use diesel_async::pooled_connection::bb8::Pool;
 use diesel_async::pooled_connection::AsyncDieselConnectionManager;
+use diesel_async::pooled_connection::ManagerConfig;
+use diesel_async::sync_connection_wrapper::SyncConnectionWrapper;
+use diesel_async::AsyncConnection;
 use diesel_migrations::embed_migrations;
 use diesel_migrations::EmbeddedMigrations;
 use diesel_migrations::MigrationHarness;
 use repository::SqliteRepositoryProvider;
 
 use crate::BackendError;
+use crate::MigrationStatus;
 
 pub mod repository;
 mod schema;
@@ -22,8 +28,38 @@ pub fn run_pending_migrations(database_url: &str) -> Result<(), BackendError> {
     Ok(())
 }
 
+pub fn migration_status(database_url: &str) -> Result<Vec<MigrationStatus>, BackendError> {
+    let mut connection = SqliteConnection::establish(database_url)?;
+    crate::migration::migration_status(&mut connection, &MIGRATIONS)
+}
+
+pub fn run_next_migrations(database_url: &str, count: Option<usize>) -> Result<Vec<String>, BackendError> {
+    let mut connection = SqliteConnection::establish(database_url)?;
+    crate::migration::run_next_migrations(&mut connection, &MIGRATIONS, count)
+}
+
+pub fn revert_last_migrations(database_url: &str, count: usize) -> Result<Vec<String>, BackendError> {
+    let mut connection = SqliteConnection::establish(database_url)?;
+    crate::migration::revert_last_migrations(&mut connection, &MIGRATIONS, count)
+}
+
 pub async fn repository_provider(database_url: &str) -> Result<SqliteRepositoryProvider, BackendError> {
-    let config = AsyncDieselConnectionManager::new(database_url);
+    let mut manager_config = ManagerConfig::default();
+    manager_config.custom_setup = Box::new(|database_url| Box::pin(establish_connection(database_url.to_string())));
+
+    let config = AsyncDieselConnectionManager::new_with_config(database_url, manager_config);
     let pool = Pool::builder().build(config).await?;
     Ok(SqliteRepositoryProvider::new(pool))
 }
+
+/// Establishes a pooled connection with a `lower` function that matches the other backends.
+async fn establish_connection(database_url: String) -> ConnectionResult<SyncConnectionWrapper<SqliteConnection>> {
+    let mut connection = SyncConnectionWrapper::<SqliteConnection>::establish(&database_url).await?;
+
+    connection
+        .spawn_blocking(|connection| crate::sql_function::lower_utils::register_impl(connection, crate::sql_function::unicode_lower))
+        .await
+        .map_err(|err| ConnectionError::BadConnection(err.to_string()))?;
+
+    Ok(connection)
+}
@@ -1,7 +1,10 @@
+use diesel::connection::AnsiTransactionManager;
 use diesel::SqliteConnection;
 use diesel_async::pooled_connection::bb8::Pool;
 use diesel_async::pooled_connection::bb8::PooledConnection;
 use diesel_async::sync_connection_wrapper::SyncConnectionWrapper;
+use diesel_async::sync_connection_wrapper::SyncTransactionManagerWrapper;
+use diesel_async::TransactionManager;
 use cipher_core::repository::Repository;
 use cipher_core::repository::RepositoryError;
 use cipher_core::repository::RepositoryProvider;
@@ -12,6 +15,8 @@ mod profile_repository;
 mod staff_role_repository;
 mod user_repository;
 
+type SqliteTransactionManager = SyncTransactionManagerWrapper<AnsiTransactionManager>;
+
 pub struct SqliteRepository<'a> {
     conn: PooledConnection<'a, SyncConnectionWrapper<SqliteConnection>>,
 }
@@ -22,8 +27,27 @@ impl<'a> SqliteRepository<'a> {
     }
 }
 
+#[async_trait::async_trait]
 impl Repository for SqliteRepository<'_> {
     type BackendError = BackendError;
+
+    async fn begin_transaction(&mut self) -> Result<(), RepositoryError<BackendError>> {
+        SqliteTransactionManager::begin_transaction(&mut *self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))
+    }
+
+    async fn commit_transaction(&mut self) -> Result<(), RepositoryError<BackendError>> {
+        SqliteTransactionManager::commit_transaction(&mut *self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))
+    }
+
+    async fn rollback_transaction(&mut self) -> Result<(), RepositoryError<BackendError>> {
+        SqliteTransactionManager::rollback_transaction(&mut *self.conn)
+            .await
+            .map_err(|err| RepositoryError(BackendError::from(err)))
+    }
 }
 
 pub struct SqliteRepositoryProvider {
@@ -25,6 +25,7 @@ diesel::table! {
     staff_roles (id) {
         id -> Integer,
         discord_role_id -> BigInt,
+        discord_guild_id -> Nullable<BigInt>,
     }
 }
 
@@ -0,0 +1,73 @@
+//! Runs the shared repository conformance suite against every enabled backend.
+//!
+//! The memory and sqlite backends are always tested. The mysql and postgres
+//! tests are ignored by default, run them with `cargo test -- --ignored` after
+//! pointing `CIPHER_TEST_MYSQL_URL` or `CIPHER_TEST_POSTGRES_URL` at a database
+//! that may be migrated and written to.
+
+#[cfg(feature = "memory")]
+mod memory {
+    use cipher_database::memory::repository::MemoryRepositoryProvider;
+
+    async fn setup(_name: &str) -> MemoryRepositoryProvider {
+        cipher_database::memory::repository_provider()
+    }
+
+    cipher_core::repository_conformance_tests!(setup);
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use std::path::PathBuf;
+
+    use cipher_database::sqlite::repository::SqliteRepositoryProvider;
+
+    async fn setup(name: &str) -> SqliteRepositoryProvider {
+        let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(format!("conformance_{}.db", name));
+        if path.exists() {
+            std::fs::remove_file(&path).expect("failed to remove previous test database");
+        }
+
+        let database_url = path.to_str().expect("test database path is not valid utf-8");
+        cipher_database::sqlite::run_pending_migrations(database_url).expect("failed to run migrations");
+        cipher_database::sqlite::repository_provider(database_url).await.expect("failed to create repository provider")
+    }
+
+    cipher_core::repository_conformance_tests!(setup);
+}
+
+#[cfg(feature = "postgres")]
+mod postgres {
+    use std::sync::Once;
+
+    use cipher_database::postgres::repository::PostgresRepositoryProvider;
+
+    async fn setup(_name: &str) -> PostgresRepositoryProvider {
+        static MIGRATIONS: Once = Once::new();
+
+        let database_url = std::env::var("CIPHER_TEST_POSTGRES_URL").expect("CIPHER_TEST_POSTGRES_URL must be set to run the postgres conformance tests");
+
+        MIGRATIONS.call_once(|| cipher_database::postgres::run_pending_migrations(&database_url).expect("failed to run migrations"));
+        cipher_database::postgres::repository_provider(&database_url).await.expect("failed to create repository provider")
+    }
+
+    cipher_core::repository_conformance_tests!(setup, ignore = "requires CIPHER_TEST_POSTGRES_URL");
+}
+
+#[cfg(feature = "mysql")]
+mod mysql {
+    use std::sync::Once;
+
+    use cipher_database::mysql::repository::MysqlRepositoryProvider;
+
+    async fn setup(_name: &str) -> MysqlRepositoryProvider {
+        static MIGRATIONS: Once = Once::new();
+
+        let database_url = std::env::var("CIPHER_TEST_MYSQL_URL").expect("CIPHER_TEST_MYSQL_URL must be set to run the mysql conformance tests");
+
+        MIGRATIONS.call_once(|| cipher_database::mysql::run_pending_migrations(&database_url).expect("failed to run migrations"));
+        cipher_database::mysql::repository_provider(&database_url).await.expect("failed to create repository provider")
+    }
+
+    cipher_core::repository_conformance_tests!(setup, ignore = "requires CIPHER_TEST_MYSQL_URL");
+}
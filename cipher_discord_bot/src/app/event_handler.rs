@@ -1,4 +1,5 @@
 use cipher_core::repository::RepositoryProvider;
+use serenity::all::ChunkGuildFilter;
 use serenity::all::FullEvent;
 
 use crate::utils;
@@ -23,6 +24,10 @@ pub async fn event_handler<R: RepositoryProvider>(
         FullEvent::CacheReady { guilds } => {
             utils::register_in_guilds(serenity_ctx, &framework_ctx.options.commands, guilds).await;
         }
+        FullEvent::GuildCreate { guild, .. } if guild.large => {
+            // Discord only sends the online members of large guilds, request the rest so the member cache is complete.
+            serenity_ctx.shard.chunk_guild(guild.id, None, false, ChunkGuildFilter::None, None);
+        }
         _ => {}
     }
 
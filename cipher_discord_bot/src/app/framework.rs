@@ -3,14 +3,24 @@ use poise::FrameworkOptions;
 use cipher_core::repository::RepositoryProvider;
 
 use crate::cli::AppInfo;
+use crate::cli::PokeApiOptions;
+use crate::cli::ProfileImageOptions;
 use crate::commands;
+use crate::pokeapi::PokedexSource;
+use crate::pokeapi::PokemonSummaryCache;
 
 use super::event_handler;
 use super::on_error;
 use super::AppData;
 use super::AppError;
 
-pub fn framework<R>(repository_provider: R, info: AppInfo) -> Framework<AppData<R>, AppError<R::BackendError>>
+pub fn framework<R>(
+    repository_provider: R,
+    info: AppInfo,
+    profile_images: ProfileImageOptions,
+    pokeapi: PokeApiOptions,
+    pokedex: Box<dyn PokedexSource>,
+) -> Framework<AppData<R>, AppError<R::BackendError>>
 where
     R: RepositoryProvider + Send + Sync + 'static,
     R::BackendError: Send + Sync,
@@ -22,6 +32,9 @@ where
         repository_provider,
         qualified_command_names: commands::qualified_command_names(&commands),
         info,
+        pokedex,
+        pokemon_summaries: PokemonSummaryCache::new(pokeapi.cache_ttl),
+        profile_images,
     };
 
     let options = FrameworkOptions::<AppData<R>, AppError<R::BackendError>> {
@@ -6,6 +6,11 @@ use serenity::Client;
 
 use crate::cli::AppInfo;
 use crate::cli::DiscordCredentials;
+use crate::cli::PokeApiOptions;
+use crate::cli::ProfileImageOptions;
+use crate::pokeapi::PokedexError;
+use crate::pokeapi::PokedexSource;
+use crate::pokeapi::PokemonSummaryCache;
 
 mod event_handler;
 mod framework;
@@ -15,12 +20,17 @@ mod on_error;
 pub enum AppStartError {
     #[error(transparent)]
     SerenityError(#[from] serenity::Error),
+    #[error(transparent)]
+    PokedexError(#[from] PokedexError),
 }
 
 pub struct AppData<R> {
     repository_provider: R,
     qualified_command_names: Vec<String>,
     info: AppInfo,
+    pokedex: Box<dyn PokedexSource>,
+    pokemon_summaries: PokemonSummaryCache,
+    profile_images: ProfileImageOptions,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -30,7 +40,13 @@ pub enum AppError<E> {
     #[error(transparent)]
     RepositoryError(#[from] RepositoryError<E>),
     #[error(transparent)]
-    RustemonError(#[from] rustemon::error::Error),
+    PokedexError(#[from] PokedexError),
+    #[error(transparent)]
+    JsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error("administrator-only command used by non-administrator user")]
+    AdministratorOnly { command_name: String },
     #[error("staff-only command used by non-staff user")]
     StaffOnly { command_name: String },
     #[error("unknown cache or http error")]
@@ -40,14 +56,22 @@ pub enum AppError<E> {
 pub type AppContext<'a, R, E> = poise::ApplicationContext<'a, AppData<R>, AppError<E>>;
 pub type AppCommand<R, E> = poise::Command<AppData<R>, AppError<E>>;
 
-pub async fn start<R>(credentials: DiscordCredentials, info: AppInfo, repository_provider: R) -> Result<(), AppStartError>
+pub async fn start<R>(
+    credentials: DiscordCredentials,
+    info: AppInfo,
+    profile_images: ProfileImageOptions,
+    pokeapi: PokeApiOptions,
+    repository_provider: R,
+) -> Result<(), AppStartError>
 where
     R: RepositoryProvider + Send + Sync + 'static,
     R::BackendError: Send + Sync,
     for<'a> R::Repository<'a>: Send + Sync,
 {
+    let pokedex = crate::pokeapi::pokedex_source(&pokeapi)?;
+
     let mut client = Client::builder(credentials.bot_token.expose_secret(), GatewayIntents::all())
-        .framework(framework::framework(repository_provider, info))
+        .framework(framework::framework(repository_provider, info, profile_images, pokeapi, pokedex))
         .await?;
 
     let shard_manager = client.shard_manager.clone();
@@ -79,4 +103,16 @@ impl<R> AppData<R> {
     pub fn info(&self) -> &AppInfo {
         &self.info
     }
+
+    pub fn pokedex(&self) -> &dyn PokedexSource {
+        self.pokedex.as_ref()
+    }
+
+    pub fn pokemon_summaries(&self) -> &PokemonSummaryCache {
+        &self.pokemon_summaries
+    }
+
+    pub fn profile_images(&self) -> &ProfileImageOptions {
+        &self.profile_images
+    }
 }
@@ -294,13 +294,33 @@ where
                 log::Level::Error,
             ),
 
-            A::RustemonError(error) => ErrorMessage::new(
+            A::PokedexError(error) => ErrorMessage::new(
                 "PokéAPI Error",
                 "Failed to get resource from Pokémon.",
                 format!("failed to get resource from PokéAPI: {}", error),
                 log::Level::Warn,
             ),
 
+            A::JsonError(error) => ErrorMessage::new(
+                "Internal Error",
+                "Please contact a bot administrator to review the logs for further details.",
+                format!("json serialization error: {}", error),
+                log::Level::Error,
+            ),
+
+            A::IoError(error) => ErrorMessage::new(
+                "Internal Error",
+                "Please contact a bot administrator to review the logs for further details.",
+                format!("io error: {}", error),
+                log::Level::Error,
+            ),
+
+            A::AdministratorOnly { command_name } => ErrorMessage::new(
+                "Administrator Only Command",
+                format!("`/{}` can only be used by server administrators.", command_name),
+                format!("administrator-only command `{}` cannot be run by non-administrator users", command_name),
+                log::Level::Info,
+            ),
             A::StaffOnly { command_name } => ErrorMessage::new(
                 "Staff Only Command",
                 format!("`/{}` can only be used by staff.", command_name),
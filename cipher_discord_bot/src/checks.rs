@@ -8,14 +8,33 @@ pub async fn is_staff<R>(ctx: poise::Context<'_, AppData<R>, AppError<R::Backend
 where
     R: RepositoryProvider,
 {
-    let roles: Vec<_> = match ctx.author_member().await {
-        Some(member) => member.roles.iter().map(|r| r.get()).collect(),
+    let (guild_id, roles): (_, Vec<_>) = match ctx.author_member().await {
+        Some(member) => (member.guild_id.get(), member.roles.iter().map(|r| r.get()).collect()),
         None => return Err(AppError::StaffOnly { command_name: ctx.command().qualified_name.clone() }),
     };
 
-    match ctx.data().repository().await?.staff_roles_contains(&roles).await {
+    match ctx.data().repository().await?.staff_roles_contains(guild_id, &roles).await {
         Ok(true) => Ok(true),
         Ok(false) => Err(AppError::StaffOnly { command_name: ctx.command().qualified_name.clone() }),
         Err(err) => Err(AppError::from(err)),
     }
 }
+
+pub async fn is_administrator<R>(ctx: poise::Context<'_, AppData<R>, AppError<R::BackendError>>) -> Result<bool, AppError<R::BackendError>>
+where
+    R: RepositoryProvider,
+{
+    if ctx.framework().options.owners.contains(&ctx.author().id) {
+        return Ok(true);
+    }
+
+    let is_administrator = match ctx.author_member().await {
+        Some(member) => member.permissions.is_some_and(|permissions| permissions.administrator()),
+        None => false,
+    };
+
+    match is_administrator {
+        true => Ok(true),
+        false => Err(AppError::AdministratorOnly { command_name: ctx.command().qualified_name.clone() }),
+    }
+}
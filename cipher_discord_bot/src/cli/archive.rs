@@ -0,0 +1,377 @@
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+use cipher_core::repository::profile_repository::NewProfile;
+use cipher_core::repository::profile_repository::Profile;
+use cipher_core::repository::staff_role_repository::StaffRole;
+use cipher_core::repository::user_repository::NewUser;
+use cipher_core::repository::Repository;
+use cipher_core::repository::RepositoryError;
+use cipher_core::repository::RepositoryProvider;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The format version written to new archives.
+///
+/// This must be incremented whenever the archive format changes in a way
+/// that older versions of the application can not read.
+pub const ARCHIVE_VERSION: u32 = 1;
+
+/// The number of users, along with their profiles, read per query when exporting.
+const EXPORT_BATCH_SIZE: u32 = 500;
+
+/// A backend independent snapshot of all data stored by the application.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Archive {
+    /// The format version of the archive.
+    pub version: u32,
+    /// The time the archive was created.
+    pub exported_at: DateTime<Utc>,
+    /// Every user along with their full profile history.
+    pub users: Vec<ArchivedUser>,
+    /// Every staff role of every guild.
+    pub staff_roles: Vec<ArchivedStaffRole>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedUser {
+    pub discord_user_id: u64,
+    /// Every profile version of the user, oldest first.
+    pub profiles: Vec<ArchivedProfile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedProfile {
+    pub thumbnail_url: Option<String>,
+    pub image_url: Option<String>,
+
+    pub trainer_class: Option<String>,
+    pub nature: Option<String>,
+    pub partner_pokemon: Option<String>,
+    pub starting_region: Option<String>,
+    pub favourite_food: Option<String>,
+    pub likes: Option<String>,
+    pub quotes: Option<String>,
+
+    pub pokemon_go_code: Option<String>,
+    pub pokemon_pocket_code: Option<String>,
+    pub switch_code: Option<String>,
+
+    pub created_at: DateTime<Utc>,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedStaffRole {
+    pub discord_role_id: u64,
+    /// The guild the role belongs to, or `None` for roles that apply in every guild.
+    pub discord_guild_id: Option<u64>,
+}
+
+/// The number of rows contained in an archive or a database.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArchiveSummary {
+    pub users: usize,
+    pub profiles: usize,
+    pub staff_roles: usize,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ArchiveError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error("unsupported archive version {0}, expected version {ARCHIVE_VERSION}")]
+    UnsupportedVersion(u32),
+    #[error("the target database already contains data, imports require an empty database")]
+    TargetNotEmpty,
+    #[error(transparent)]
+    RepositoryBackendError(#[from] cipher_database::BackendError),
+}
+
+impl From<RepositoryError<cipher_database::BackendError>> for ArchiveError {
+    fn from(value: RepositoryError<cipher_database::BackendError>) -> Self {
+        ArchiveError::RepositoryBackendError(value.0)
+    }
+}
+
+impl Archive {
+    /// Returns the number of rows contained in the archive.
+    pub fn summary(&self) -> ArchiveSummary {
+        ArchiveSummary {
+            users: self.users.len(),
+            profiles: self.users.iter().map(|user| user.profiles.len()).sum(),
+            staff_roles: self.staff_roles.len(),
+        }
+    }
+}
+
+impl std::fmt::Display for ArchiveSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} users, {} profiles, {} staff roles", self.users, self.profiles, self.staff_roles)
+    }
+}
+
+/// Reads every user, profile and staff role from a repository into an archive.
+///
+/// All rows are read in a single transaction, so the archive is a consistent snapshot
+/// even while the application is writing to the repository.
+pub async fn export_archive<R>(repository_provider: &R) -> Result<Archive, ArchiveError>
+where
+    R: RepositoryProvider<BackendError = cipher_database::BackendError>,
+{
+    let mut repository = repository_provider.get().await?;
+
+    repository.begin_transaction().await?;
+
+    match read_archive(&mut repository).await {
+        Ok(archive) => {
+            repository.commit_transaction().await?;
+            Ok(archive)
+        },
+        Err(err) => {
+            repository.rollback_transaction().await?;
+            Err(err)
+        },
+    }
+}
+
+async fn read_archive<R>(repository: &mut R) -> Result<Archive, ArchiveError>
+where
+    R: Repository<BackendError = cipher_database::BackendError>,
+{
+    let mut users = Vec::new();
+    let mut last_user_id = 0;
+
+    loop {
+        let batch = repository.users_after(last_user_id, EXPORT_BATCH_SIZE).await?;
+
+        let last_user = match batch.last() {
+            Some(last_user) => last_user,
+            None => break,
+        };
+        last_user_id = last_user.id;
+
+        let user_ids: Vec<_> = batch.iter().map(|user| user.id).collect();
+
+        let mut profiles_by_user_id: HashMap<i32, Vec<ArchivedProfile>> = HashMap::with_capacity(batch.len());
+        for profile in repository.profiles_by_user_ids(&user_ids).await? {
+            profiles_by_user_id.entry(profile.user_id).or_default().push(ArchivedProfile::from(profile));
+        }
+
+        for user in batch {
+            let mut profiles = profiles_by_user_id.remove(&user.id).unwrap_or_default();
+
+            // Profile history is returned newest first.
+            profiles.reverse();
+
+            users.push(ArchivedUser {
+                discord_user_id: user.discord_user_id,
+                profiles,
+            });
+        }
+    }
+
+    let staff_roles = repository.all_staff_roles().await?
+        .into_iter()
+        .map(ArchivedStaffRole::from)
+        .collect();
+
+    Ok(Archive {
+        version: ARCHIVE_VERSION,
+        exported_at: Utc::now(),
+        users,
+        staff_roles,
+    })
+}
+
+/// Writes every user, profile and staff role in an archive to an empty repository.
+///
+/// Database ids are reassigned by the target repository. Profile creation times
+/// and active states are preserved. All rows are written in a single transaction,
+/// so a failed import leaves the repository empty and can be rerun.
+pub async fn import_archive<R>(repository_provider: &R, archive: Archive) -> Result<ArchiveSummary, ArchiveError>
+where
+    R: RepositoryProvider<BackendError = cipher_database::BackendError>,
+{
+    if archive.version != ARCHIVE_VERSION {
+        return Err(ArchiveError::UnsupportedVersion(archive.version));
+    }
+
+    let mut repository = repository_provider.get().await?;
+
+    repository.begin_transaction().await?;
+
+    match write_archive(&mut repository, archive).await {
+        Ok(summary) => {
+            repository.commit_transaction().await?;
+            Ok(summary)
+        },
+        Err(err) => {
+            repository.rollback_transaction().await?;
+            Err(err)
+        },
+    }
+}
+
+async fn write_archive<R>(repository: &mut R, archive: Archive) -> Result<ArchiveSummary, ArchiveError>
+where
+    R: Repository<BackendError = cipher_database::BackendError>,
+{
+    if !repository.users().await?.is_empty() || !repository.all_staff_roles().await?.is_empty() {
+        return Err(ArchiveError::TargetNotEmpty);
+    }
+
+    let mut summary = ArchiveSummary::default();
+
+    for archived_user in archive.users {
+        let user = repository.insert_user(NewUser { discord_user_id: archived_user.discord_user_id }).await?;
+        summary.users += 1;
+
+        for archived_profile in archived_user.profiles {
+            let created_at = archived_profile.created_at;
+            let is_active = archived_profile.is_active;
+            let new_profile = archived_profile.into_new(user.id);
+
+            repository.insert_profile_version(new_profile, created_at, is_active).await?;
+            summary.profiles += 1;
+        }
+    }
+
+    for archived_staff_role in archive.staff_roles {
+        repository.insert_staff_role(StaffRole::from(archived_staff_role)).await?;
+        summary.staff_roles += 1;
+    }
+
+    Ok(summary)
+}
+
+impl ArchivedProfile {
+    fn into_new(self, user_id: i32) -> NewProfile {
+        NewProfile {
+            user_id,
+
+            thumbnail_url: self.thumbnail_url,
+            image_url: self.image_url,
+
+            trainer_class: self.trainer_class,
+            nature: self.nature,
+            partner_pokemon: self.partner_pokemon,
+            starting_region: self.starting_region,
+            favourite_food: self.favourite_food,
+            likes: self.likes,
+            quotes: self.quotes,
+
+            pokemon_go_code: self.pokemon_go_code,
+            pokemon_pocket_code: self.pokemon_pocket_code,
+            switch_code: self.switch_code,
+        }
+    }
+}
+
+impl From<Profile> for ArchivedProfile {
+    fn from(value: Profile) -> Self {
+        Self {
+            thumbnail_url: value.thumbnail_url,
+            image_url: value.image_url,
+
+            trainer_class: value.trainer_class,
+            nature: value.nature,
+            partner_pokemon: value.partner_pokemon,
+            starting_region: value.starting_region,
+            favourite_food: value.favourite_food,
+            likes: value.likes,
+            quotes: value.quotes,
+
+            pokemon_go_code: value.pokemon_go_code,
+            pokemon_pocket_code: value.pokemon_pocket_code,
+            switch_code: value.switch_code,
+
+            created_at: value.created_at,
+            is_active: value.is_active,
+        }
+    }
+}
+
+impl From<StaffRole> for ArchivedStaffRole {
+    fn from(value: StaffRole) -> Self {
+        Self {
+            discord_role_id: value.discord_role_id,
+            discord_guild_id: value.discord_guild_id,
+        }
+    }
+}
+
+impl From<ArchivedStaffRole> for StaffRole {
+    fn from(value: ArchivedStaffRole) -> Self {
+        Self {
+            discord_role_id: value.discord_role_id,
+            discord_guild_id: value.discord_guild_id,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "memory", feature = "sqlite"))]
+mod tests {
+    use cipher_core::repository::RepositoryProvider;
+
+    use super::export_archive;
+    use super::import_archive;
+    use super::ArchiveError;
+    use super::ArchiveSummary;
+    use crate::cli::testing::contents;
+    use crate::cli::testing::seed;
+    use crate::cli::testing::TempSqlite;
+
+    #[tokio::test]
+    async fn archives_round_trip_between_backends() {
+        let source = cipher_database::memory::repository_provider();
+        seed(&mut source.get().await.unwrap(), 3).await;
+
+        let archive = export_archive(&source).await.unwrap();
+        assert_eq!(archive.summary(), ArchiveSummary { users: 3, profiles: 6, staff_roles: 2 });
+
+        let target = TempSqlite::new().await;
+        let imported = import_archive(&target.provider, archive.clone()).await.unwrap();
+        assert_eq!(imported, archive.summary());
+
+        let reexported = export_archive(&target.provider).await.unwrap();
+        assert_eq!(contents(&reexported), contents(&archive));
+
+        // Each user keeps their older inactive version and newer active version.
+        for user in &reexported.users {
+            let is_active: Vec<_> = user.profiles.iter().map(|profile| profile.is_active).collect();
+            assert_eq!(is_active, vec![false, true]);
+        }
+    }
+
+    #[tokio::test]
+    async fn import_into_non_empty_target_is_rejected() {
+        let source = cipher_database::memory::repository_provider();
+        seed(&mut source.get().await.unwrap(), 2).await;
+        let archive = export_archive(&source).await.unwrap();
+
+        let target = TempSqlite::new().await;
+        seed(&mut target.provider.get().await.unwrap(), 1).await;
+        let before = export_archive(&target.provider).await.unwrap();
+
+        let result = import_archive(&target.provider, archive).await;
+        assert!(matches!(result, Err(ArchiveError::TargetNotEmpty)));
+
+        let after = export_archive(&target.provider).await.unwrap();
+        assert_eq!(contents(&after), contents(&before));
+    }
+
+    #[tokio::test]
+    async fn unsupported_archive_versions_are_rejected() {
+        let source = cipher_database::memory::repository_provider();
+        let mut archive = export_archive(&source).await.unwrap();
+        archive.version += 1;
+
+        let target = TempSqlite::new().await;
+        let result = import_archive(&target.provider, archive).await;
+        assert!(matches!(result, Err(ArchiveError::UnsupportedVersion(_))));
+    }
+}
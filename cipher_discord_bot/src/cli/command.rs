@@ -9,19 +9,63 @@ pub enum Command {
         about,
         long_about = None,
     )]
-    Start(super::start::Start),
+    Start(Box<super::start::Start>),
+
+    /// Manage database migrations.
+    #[command(
+        name = "migrate",
+        about,
+        long_about = None,
+    )]
+    Migrate(super::migrate::Migrate),
+
+    /// Export all data to a JSON archive.
+    #[command(
+        name = "export",
+        about,
+        long_about = None,
+    )]
+    Export(super::export::Export),
+
+    /// Import all data from a JSON archive into an empty database.
+    #[command(
+        name = "import",
+        about,
+        long_about = None,
+    )]
+    Import(super::import::Import),
+
+    /// Copy all data from one database into another empty database.
+    #[command(
+        name = "transfer",
+        about,
+        long_about = None,
+    )]
+    Transfer(super::transfer::Transfer),
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum CommandError {
     #[error(transparent)]
-    StartError(#[from] super::start::StartError),
+    Start(#[from] super::start::StartError),
+    #[error(transparent)]
+    Migrate(#[from] super::migrate::MigrateError),
+    #[error(transparent)]
+    Export(#[from] super::export::ExportError),
+    #[error(transparent)]
+    Import(#[from] super::import::ImportError),
+    #[error(transparent)]
+    Transfer(#[from] super::transfer::TransferError),
 }
 
 impl Command {
     pub async fn execute(self) -> Result<(), CommandError> {
         match self {
             Command::Start(start) => start.execute().await?,
+            Command::Migrate(migrate) => migrate.execute().await?,
+            Command::Export(export) => export.execute().await?,
+            Command::Import(import) => import.execute().await?,
+            Command::Transfer(transfer) => transfer.execute().await?,
         }
 
         Ok(())
@@ -0,0 +1,83 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::PathBuf;
+
+use cipher_core::repository::RepositoryProvider;
+use clap::Parser;
+use secrecy::ExposeSecret;
+
+use super::archive::ArchiveError;
+use super::DatabaseCredentials;
+
+/// Export all data to a JSON archive.
+#[derive(Debug, Clone, Parser)]
+pub struct Export {
+    /// Credentials required to establish a database connection.
+    #[command(flatten)]
+    pub database: DatabaseCredentials,
+
+    /// The path of the JSON archive to write. Existing files are overwritten.
+    #[arg(short = 'o', long = "output")]
+    pub path: PathBuf,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error(transparent)]
+    RepositoryBackendError(#[from] cipher_database::BackendError),
+    #[error(transparent)]
+    ArchiveError(#[from] ArchiveError),
+}
+
+impl Export {
+    pub async fn execute(self) -> Result<(), ExportError> {
+        log::debug!("{:#?}", self);
+
+        #[allow(unused)]
+        let database_url = self.database.url.expose_secret();
+
+        match self.database.dialect {
+            #[cfg(feature = "memory")]
+            crate::cli::DatabaseDialect::Memory => {
+                log::warn!("Exporting an in-memory database. The archive will not contain any data.");
+                let repository_provider = cipher_database::memory::repository_provider();
+                self.export(&repository_provider).await?;
+            },
+            #[cfg(feature = "mysql")]
+            crate::cli::DatabaseDialect::Mysql => {
+                let repository_provider = cipher_database::mysql::repository_provider(database_url).await?;
+                self.export(&repository_provider).await?;
+            },
+            #[cfg(feature = "postgres")]
+            crate::cli::DatabaseDialect::Postgres => {
+                let repository_provider = cipher_database::postgres::repository_provider(database_url).await?;
+                self.export(&repository_provider).await?;
+            },
+            #[cfg(feature = "sqlite")]
+            crate::cli::DatabaseDialect::Sqlite => {
+                let repository_provider = cipher_database::sqlite::repository_provider(database_url).await?;
+                self.export(&repository_provider).await?;
+            },
+        }
+
+        Ok(())
+    }
+
+    async fn export<R>(&self, repository_provider: &R) -> Result<(), ArchiveError>
+    where
+        R: RepositoryProvider<BackendError = cipher_database::BackendError>,
+    {
+        log::info!("Reading data from the database.");
+        let archive = super::archive::export_archive(repository_provider).await?;
+
+        log::info!("Writing archive to {}.", self.path.display());
+        let mut writer = BufWriter::new(File::create(&self.path)?);
+        serde_json::to_writer_pretty(&mut writer, &archive)?;
+        writer.flush()?;
+
+        println!("Exported {} to {}", archive.summary(), self.path.display());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+use cipher_core::repository::RepositoryProvider;
+use clap::Parser;
+use secrecy::ExposeSecret;
+
+use super::archive::Archive;
+use super::archive::ArchiveError;
+use super::DatabaseCredentials;
+
+/// Import all data from a JSON archive into an empty database.
+#[derive(Debug, Clone, Parser)]
+pub struct Import {
+    /// Credentials required to establish a database connection.
+    #[command(flatten)]
+    pub database: DatabaseCredentials,
+
+    /// The path of the JSON archive to read.
+    #[arg(short = 'i', long = "input")]
+    pub path: PathBuf,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportError {
+    #[error(transparent)]
+    RepositoryBackendError(#[from] cipher_database::BackendError),
+    #[error(transparent)]
+    ArchiveError(#[from] ArchiveError),
+    #[error("the memory dialect can not be imported into, its data is lost when the command exits")]
+    MemoryDialect,
+}
+
+impl Import {
+    pub async fn execute(self) -> Result<(), ImportError> {
+        log::debug!("{:#?}", self);
+
+        #[allow(unused)]
+        let database_url = self.database.url.expose_secret();
+
+        log::info!("Reading archive from {}.", self.path.display());
+        let reader = BufReader::new(File::open(&self.path).map_err(ArchiveError::from)?);
+        let archive: Archive = serde_json::from_reader(reader).map_err(ArchiveError::from)?;
+
+        match self.database.dialect {
+            #[cfg(feature = "memory")]
+            crate::cli::DatabaseDialect::Memory => {
+                return Err(ImportError::MemoryDialect);
+            },
+            #[cfg(feature = "mysql")]
+            crate::cli::DatabaseDialect::Mysql => {
+                log::info!("Running any pending database migrations.");
+                cipher_database::mysql::run_pending_migrations(database_url)?;
+                let repository_provider = cipher_database::mysql::repository_provider(database_url).await?;
+                import(&repository_provider, archive).await?;
+            },
+            #[cfg(feature = "postgres")]
+            crate::cli::DatabaseDialect::Postgres => {
+                log::info!("Running any pending database migrations.");
+                cipher_database::postgres::run_pending_migrations(database_url)?;
+                let repository_provider = cipher_database::postgres::repository_provider(database_url).await?;
+                import(&repository_provider, archive).await?;
+            },
+            #[cfg(feature = "sqlite")]
+            crate::cli::DatabaseDialect::Sqlite => {
+                log::info!("Running any pending database migrations.");
+                cipher_database::sqlite::run_pending_migrations(database_url)?;
+                let repository_provider = cipher_database::sqlite::repository_provider(database_url).await?;
+                import(&repository_provider, archive).await?;
+            },
+        }
+
+        Ok(())
+    }
+}
+
+async fn import<R>(repository_provider: &R, archive: Archive) -> Result<(), ArchiveError>
+where
+    R: RepositoryProvider<BackendError = cipher_database::BackendError>,
+{
+    log::info!("Writing data to the database.");
+    let summary = super::archive::import_archive(repository_provider, archive).await?;
+
+    println!("Imported {}", summary);
+
+    Ok(())
+}
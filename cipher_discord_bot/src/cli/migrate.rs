@@ -0,0 +1,166 @@
+use clap::Parser;
+use clap::Subcommand;
+use secrecy::ExposeSecret;
+
+use super::DatabaseCredentials;
+
+/// Manage database migrations.
+#[derive(Debug, Clone, Parser)]
+pub struct Migrate {
+    /// Credentials required to establish a database connection.
+    #[command(flatten)]
+    pub database: DatabaseCredentials,
+
+    /// The migration operation to perform.
+    #[command(subcommand)]
+    pub command: MigrateCommand,
+}
+
+/// Migration operation of the migrate command.
+#[derive(Debug, Clone, Subcommand)]
+pub enum MigrateCommand {
+    /// List applied and pending migrations.
+    Status,
+
+    /// Apply pending migrations.
+    Up {
+        /// The number of pending migrations to apply. Defaults to all pending migrations.
+        #[arg(short = 'n', long = "count")]
+        count: Option<usize>,
+    },
+
+    /// Revert the most recently applied migrations.
+    Down {
+        /// The number of applied migrations to revert.
+        #[arg(short = 'n', long = "count", default_value_t = 1)]
+        count: usize,
+    },
+
+    /// Revert and then reapply the most recently applied migrations.
+    /// Refuses to run while any migrations are pending.
+    Redo {
+        /// The number of applied migrations to redo.
+        #[arg(short = 'n', long = "count", default_value_t = 1)]
+        count: usize,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MigrateError {
+    #[error(transparent)]
+    RepositoryBackendError(#[from] cipher_database::BackendError),
+    #[error("{0} pending migrations must be applied or reverted before migrations can be redone")]
+    PendingMigrations(usize),
+}
+
+/// The migration operations of a single database dialect.
+#[cfg(any(feature = "mysql", feature = "postgres", feature = "sqlite"))]
+struct DialectMigrations {
+    migration_status: fn(&str) -> Result<Vec<cipher_database::MigrationStatus>, cipher_database::BackendError>,
+    run_next_migrations: fn(&str, Option<usize>) -> Result<Vec<String>, cipher_database::BackendError>,
+    revert_last_migrations: fn(&str, usize) -> Result<Vec<String>, cipher_database::BackendError>,
+}
+
+impl Migrate {
+    pub async fn execute(self) -> Result<(), MigrateError> {
+        log::debug!("{:#?}", self);
+
+        #[allow(unused)]
+        let database_url = self.database.url.expose_secret();
+
+        #[allow(unused)]
+        let migrations = match self.database.dialect {
+            #[cfg(feature = "memory")]
+            crate::cli::DatabaseDialect::Memory => {
+                println!("The memory dialect does not use migrations.");
+                return Ok(());
+            },
+            #[cfg(feature = "mysql")]
+            crate::cli::DatabaseDialect::Mysql => DialectMigrations {
+                migration_status: cipher_database::mysql::migration_status,
+                run_next_migrations: cipher_database::mysql::run_next_migrations,
+                revert_last_migrations: cipher_database::mysql::revert_last_migrations,
+            },
+            #[cfg(feature = "postgres")]
+            crate::cli::DatabaseDialect::Postgres => DialectMigrations {
+                migration_status: cipher_database::postgres::migration_status,
+                run_next_migrations: cipher_database::postgres::run_next_migrations,
+                revert_last_migrations: cipher_database::postgres::revert_last_migrations,
+            },
+            #[cfg(feature = "sqlite")]
+            crate::cli::DatabaseDialect::Sqlite => DialectMigrations {
+                migration_status: cipher_database::sqlite::migration_status,
+                run_next_migrations: cipher_database::sqlite::run_next_migrations,
+                revert_last_migrations: cipher_database::sqlite::revert_last_migrations,
+            },
+        };
+
+        #[cfg(any(feature = "mysql", feature = "postgres", feature = "sqlite"))]
+        self.command.execute(&migrations, database_url)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(any(feature = "mysql", feature = "postgres", feature = "sqlite"))]
+impl MigrateCommand {
+    fn execute(&self, migrations: &DialectMigrations, database_url: &str) -> Result<(), MigrateError> {
+        match *self {
+            MigrateCommand::Status => {
+                let statuses = (migrations.migration_status)(database_url)?;
+
+                if statuses.is_empty() {
+                    println!("No migrations found.");
+                }
+
+                for status in statuses {
+                    let marker = match status.is_applied {
+                        true => "[X]",
+                        false => "[ ]",
+                    };
+                    println!("{} {}", marker, status.name);
+                }
+            },
+            MigrateCommand::Up { count } => {
+                let applied = (migrations.run_next_migrations)(database_url, count)?;
+                print_migrations("Applied", "No pending migrations to apply.", &applied);
+            },
+            MigrateCommand::Down { count } => {
+                let reverted = (migrations.revert_last_migrations)(database_url, count)?;
+                print_migrations("Reverted", "No applied migrations to revert.", &reverted);
+            },
+            MigrateCommand::Redo { count } => {
+                // Pending migrations would be applied in place of the reverted ones.
+                let pending = (migrations.migration_status)(database_url)?
+                    .into_iter()
+                    .filter(|status| !status.is_applied)
+                    .count();
+
+                if pending > 0 {
+                    return Err(MigrateError::PendingMigrations(pending));
+                }
+
+                let reverted = (migrations.revert_last_migrations)(database_url, count)?;
+                print_migrations("Reverted", "No applied migrations to redo.", &reverted);
+
+                if !reverted.is_empty() {
+                    let applied = (migrations.run_next_migrations)(database_url, Some(reverted.len()))?;
+                    print_migrations("Applied", "No pending migrations to apply.", &applied);
+                }
+            },
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(any(feature = "mysql", feature = "postgres", feature = "sqlite"))]
+fn print_migrations(action: &str, empty_message: &str, names: &[String]) {
+    if names.is_empty() {
+        println!("{}", empty_message);
+    }
+
+    for name in names {
+        println!("{} {}", action, name);
+    }
+}
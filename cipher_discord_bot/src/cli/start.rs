@@ -4,6 +4,8 @@ use secrecy::ExposeSecret;
 use super::AppInfo;
 use super::DatabaseCredentials;
 use super::DiscordCredentials;
+use super::PokeApiOptions;
+use super::ProfileImageOptions;
 
 /// Start the main discord bot application.
 #[derive(Debug, Clone, Parser)]
@@ -19,6 +21,14 @@ pub struct Start {
     /// Information about the application
     #[command(flatten)]
     pub info: AppInfo,
+
+    /// Restrictions applied to the profile images members link to.
+    #[command(flatten)]
+    pub profile_images: ProfileImageOptions,
+
+    /// Configuration of the client used to query PokéAPI.
+    #[command(flatten)]
+    pub pokeapi: PokeApiOptions,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -33,16 +43,24 @@ impl Start {
     pub async fn execute(self) -> Result<(), StartError> {
         log::debug!("{:#?}", self);
 
+        #[allow(unused)]
         let database_url = self.database.url.expose_secret();
 
         match self.database.dialect {
+            #[cfg(feature = "memory")]
+            crate::cli::DatabaseDialect::Memory => {
+                log::warn!("Using an in-memory database. All data will be lost when the application stops.");
+                let repository_provider = cipher_database::memory::repository_provider();
+                log::info!("Starting discord application.");
+                crate::app::start(self.discord, self.info, self.profile_images, self.pokeapi, repository_provider).await?;
+            },
             #[cfg(feature = "mysql")]
             crate::cli::DatabaseDialect::Mysql => {
                 log::info!("Running any pending database migrations.");
                 cipher_database::mysql::run_pending_migrations(database_url)?;
                 let repository_provider = cipher_database::mysql::repository_provider(database_url).await?;
                 log::info!("Starting discord application.");
-                crate::app::start(self.discord, self.info, repository_provider).await?;
+                crate::app::start(self.discord, self.info, self.profile_images, self.pokeapi, repository_provider).await?;
             },
             #[cfg(feature = "postgres")]
             crate::cli::DatabaseDialect::Postgres => {
@@ -50,7 +68,7 @@ impl Start {
                 cipher_database::postgres::run_pending_migrations(database_url)?;
                 let repository_provider = cipher_database::postgres::repository_provider(database_url).await?;
                 log::info!("Starting discord application.");
-                crate::app::start(self.discord, self.info, repository_provider).await?;
+                crate::app::start(self.discord, self.info, self.profile_images, self.pokeapi, repository_provider).await?;
             },
             #[cfg(feature = "sqlite")]
             crate::cli::DatabaseDialect::Sqlite => {
@@ -58,7 +76,7 @@ impl Start {
                 cipher_database::sqlite::run_pending_migrations(database_url)?;
                 let repository_provider = cipher_database::sqlite::repository_provider(database_url).await?;
                 log::info!("Starting discord application.");
-                crate::app::start(self.discord, self.info, repository_provider).await?;
+                crate::app::start(self.discord, self.info, self.profile_images, self.pokeapi, repository_provider).await?;
             },
         }
 
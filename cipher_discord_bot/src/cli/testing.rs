@@ -0,0 +1,87 @@
+//! Databases and data shared by the tests of the commands that copy data between databases.
+
+use std::path::PathBuf;
+
+use chrono::DateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use cipher_core::repository::profile_repository::NewProfile;
+use cipher_core::repository::staff_role_repository::StaffRole;
+use cipher_core::repository::user_repository::NewUser;
+use cipher_core::repository::Repository;
+use cipher_database::sqlite::repository::SqliteRepositoryProvider;
+use uuid::Uuid;
+
+use super::archive::Archive;
+
+/// A migrated sqlite database in a temporary file, removed when dropped.
+pub struct TempSqlite {
+    path: PathBuf,
+    pub provider: SqliteRepositoryProvider,
+}
+
+impl TempSqlite {
+    pub async fn new() -> Self {
+        let path = std::env::temp_dir().join(format!("cipher-cli-{}.db", Uuid::new_v4()));
+
+        let database_url = path.to_str().expect("test database path is not valid utf-8");
+        cipher_database::sqlite::run_pending_migrations(database_url).expect("failed to run migrations");
+        let provider = cipher_database::sqlite::repository_provider(database_url).await.expect("failed to create repository provider");
+
+        Self { path, provider }
+    }
+}
+
+impl Drop for TempSqlite {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Writes `user_count` users, each with an inactive and a newer active profile version,
+/// along with a guild scoped and a global staff role.
+pub async fn seed<R>(repository: &mut R, user_count: u64)
+where
+    R: Repository<BackendError = cipher_database::BackendError>,
+{
+    for n in 0..user_count {
+        let user = repository.insert_user(NewUser { discord_user_id: 1000 + n }).await.expect("failed to insert user");
+
+        let older = NewProfile {
+            user_id: user.id,
+            trainer_class: Some(format!("Youngster {}", n)),
+            ..Default::default()
+        };
+
+        let newer = NewProfile {
+            user_id: user.id,
+            trainer_class: Some(format!("Ace Trainer {}", n)),
+            switch_code: Some(format!("SW-0000-0000-{:04}", n)),
+            ..Default::default()
+        };
+
+        repository.insert_profile_version(older, created_at(n, 0), false).await.expect("failed to insert profile version");
+        repository.insert_profile_version(newer, created_at(n, 1), true).await.expect("failed to insert profile version");
+    }
+
+    repository.insert_staff_role(StaffRole { discord_role_id: 1, discord_guild_id: Some(10) }).await.expect("failed to insert staff role");
+    repository.insert_staff_role(StaffRole { discord_role_id: 2, discord_guild_id: None }).await.expect("failed to insert staff role");
+}
+
+/// The users, profile versions and staff roles of an archive, in a form that can be compared.
+pub fn contents(archive: &Archive) -> (serde_json::Value, serde_json::Value) {
+    let mut staff_roles = archive.staff_roles.clone();
+    staff_roles.sort_by_key(|staff_role| (staff_role.discord_role_id, staff_role.discord_guild_id));
+
+    (
+        serde_json::to_value(&archive.users).expect("failed to serialize users"),
+        serde_json::to_value(&staff_roles).expect("failed to serialize staff roles"),
+    )
+}
+
+/// Whole seconds, as not every backend stores fractions of a second.
+fn created_at(user_number: u64, version: u64) -> DateTime<Utc> {
+    let day = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+    day + chrono::Duration::hours((user_number * 24 + version) as i64)
+}
@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+
+use cipher_core::repository::user_repository::NewUser;
+use cipher_core::repository::Repository;
+use cipher_core::repository::RepositoryError;
+use cipher_core::repository::RepositoryProvider;
+use clap::Parser;
+use secrecy::ExposeSecret;
+use secrecy::SecretString;
+
+use super::archive::ArchiveSummary;
+use super::DatabaseDialect;
+
+/// Copy all data from one database into another empty database.
+#[derive(Debug, Clone, Parser)]
+pub struct Transfer {
+    /// The dialect of the database to copy data from.
+    #[arg(
+        short = None,
+        long = "from-dialect",
+        env = "FROM_DATABASE_DIALECT",
+    )]
+    pub from_dialect: DatabaseDialect,
+
+    /// The URL of the database to copy data from.
+    #[arg(
+        short = None,
+        long = "from-url",
+        env = "FROM_DATABASE_URL",
+        hide_env_values(true),
+    )]
+    pub from_url: SecretString,
+
+    /// The dialect of the database to copy data to.
+    #[arg(
+        short = None,
+        long = "to-dialect",
+        env = "TO_DATABASE_DIALECT",
+    )]
+    pub to_dialect: DatabaseDialect,
+
+    /// The URL of the database to copy data to. Pending migrations are
+    /// applied to this database before any data is copied.
+    #[arg(
+        short = None,
+        long = "to-url",
+        env = "TO_DATABASE_URL",
+        hide_env_values(true),
+    )]
+    pub to_url: SecretString,
+
+    /// The number of users, along with their profiles, to copy per batch.
+    #[arg(
+        short = None,
+        long = "batch-size",
+        default_value_t = 500,
+        value_parser = clap::value_parser!(u32).range(1..),
+    )]
+    pub batch_size: u32,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransferError {
+    #[error(transparent)]
+    RepositoryBackendError(#[from] cipher_database::BackendError),
+    #[error("the target database already contains data, transfers require an empty database")]
+    TargetNotEmpty,
+    #[error("the memory dialect can not be transferred to, its data is lost when the command exits")]
+    MemoryTarget,
+    #[error("row counts do not match after the transfer, source has {source_counts} but target has {target_counts}")]
+    RowCountMismatch {
+        source_counts: ArchiveSummary,
+        target_counts: ArchiveSummary,
+    },
+}
+
+impl From<RepositoryError<cipher_database::BackendError>> for TransferError {
+    fn from(value: RepositoryError<cipher_database::BackendError>) -> Self {
+        TransferError::RepositoryBackendError(value.0)
+    }
+}
+
+impl Transfer {
+    pub async fn execute(self) -> Result<(), TransferError> {
+        log::debug!("{:#?}", self);
+
+        #[allow(unused)]
+        let from_url = self.from_url.expose_secret();
+
+        match self.from_dialect {
+            #[cfg(feature = "memory")]
+            DatabaseDialect::Memory => {
+                log::warn!("Transferring from an in-memory database. There is no data to copy.");
+                let source = cipher_database::memory::repository_provider();
+                self.transfer_from(&source).await?;
+            },
+            #[cfg(feature = "mysql")]
+            DatabaseDialect::Mysql => {
+                let source = cipher_database::mysql::repository_provider(from_url).await?;
+                self.transfer_from(&source).await?;
+            },
+            #[cfg(feature = "postgres")]
+            DatabaseDialect::Postgres => {
+                let source = cipher_database::postgres::repository_provider(from_url).await?;
+                self.transfer_from(&source).await?;
+            },
+            #[cfg(feature = "sqlite")]
+            DatabaseDialect::Sqlite => {
+                let source = cipher_database::sqlite::repository_provider(from_url).await?;
+                self.transfer_from(&source).await?;
+            },
+        }
+
+        Ok(())
+    }
+
+    async fn transfer_from<S>(&self, source: &S) -> Result<(), TransferError>
+    where
+        S: RepositoryProvider<BackendError = cipher_database::BackendError>,
+    {
+        #[allow(unused)]
+        let to_url = self.to_url.expose_secret();
+
+        match self.to_dialect {
+            #[cfg(feature = "memory")]
+            DatabaseDialect::Memory => {
+                return Err(TransferError::MemoryTarget);
+            },
+            #[cfg(feature = "mysql")]
+            DatabaseDialect::Mysql => {
+                log::info!("Running any pending database migrations on the target database.");
+                cipher_database::mysql::run_pending_migrations(to_url)?;
+                let target = cipher_database::mysql::repository_provider(to_url).await?;
+                transfer(source, &target, self.batch_size).await?;
+            },
+            #[cfg(feature = "postgres")]
+            DatabaseDialect::Postgres => {
+                log::info!("Running any pending database migrations on the target database.");
+                cipher_database::postgres::run_pending_migrations(to_url)?;
+                let target = cipher_database::postgres::repository_provider(to_url).await?;
+                transfer(source, &target, self.batch_size).await?;
+            },
+            #[cfg(feature = "sqlite")]
+            DatabaseDialect::Sqlite => {
+                log::info!("Running any pending database migrations on the target database.");
+                cipher_database::sqlite::run_pending_migrations(to_url)?;
+                let target = cipher_database::sqlite::repository_provider(to_url).await?;
+                transfer(source, &target, self.batch_size).await?;
+            },
+        }
+
+        Ok(())
+    }
+}
+
+/// Copies every user, profile and staff role from `source` into the empty `target`.
+///
+/// Users are copied in batches of `batch_size` in ascending id order, each along
+/// with its full profile history. Row counts of both databases are compared once
+/// all data has been copied. All rows are written in a single transaction, so a
+/// failed transfer leaves the target empty and can be rerun.
+async fn transfer<S, T>(source: &S, target: &T, batch_size: u32) -> Result<(), TransferError>
+where
+    S: RepositoryProvider<BackendError = cipher_database::BackendError>,
+    T: RepositoryProvider<BackendError = cipher_database::BackendError>,
+{
+    let mut source_repository = source.get().await?;
+    let mut target_repository = target.get().await?;
+
+    target_repository.begin_transaction().await?;
+
+    match copy_rows(&mut source_repository, &mut target_repository, batch_size).await {
+        Ok(transferred) => {
+            target_repository.commit_transaction().await?;
+            println!("Transferred {}", transferred);
+            Ok(())
+        },
+        Err(err) => {
+            target_repository.rollback_transaction().await?;
+            Err(err)
+        },
+    }
+}
+
+async fn copy_rows<S, T>(source_repository: &mut S, target_repository: &mut T, batch_size: u32) -> Result<ArchiveSummary, TransferError>
+where
+    S: Repository<BackendError = cipher_database::BackendError>,
+    T: Repository<BackendError = cipher_database::BackendError>,
+{
+    if row_counts(target_repository).await? != ArchiveSummary::default() {
+        return Err(TransferError::TargetNotEmpty);
+    }
+
+    let source_counts = row_counts(source_repository).await?;
+    log::info!("Transferring {}.", source_counts);
+
+    let mut transferred = ArchiveSummary::default();
+    let mut last_user_id = 0;
+
+    loop {
+        let users = source_repository.users_after(last_user_id, batch_size).await?;
+
+        let last_user = match users.last() {
+            Some(last_user) => last_user,
+            None => break,
+        };
+        last_user_id = last_user.id;
+
+        let mut target_user_ids = HashMap::with_capacity(users.len());
+        for user in &users {
+            let target_user = target_repository.insert_user(NewUser { discord_user_id: user.discord_user_id }).await?;
+            target_user_ids.insert(user.id, target_user.id);
+        }
+
+        let user_ids: Vec<_> = users.iter().map(|user| user.id).collect();
+
+        // Profile history is returned newest first within each user.
+        let profiles = source_repository.profiles_by_user_ids(&user_ids).await?;
+        for profile in profiles.into_iter().rev() {
+            let created_at = profile.created_at;
+            let is_active = profile.is_active;
+            let mut new_profile = profile.into_new();
+            new_profile.user_id = target_user_ids[&new_profile.user_id];
+
+            target_repository.insert_profile_version(new_profile, created_at, is_active).await?;
+            transferred.profiles += 1;
+        }
+
+        transferred.users += users.len();
+        log::info!("Transferred {} of {} users.", transferred.users, source_counts.users);
+    }
+
+    for staff_role in source_repository.all_staff_roles().await? {
+        target_repository.insert_staff_role(staff_role).await?;
+        transferred.staff_roles += 1;
+    }
+
+    log::info!("Verifying row counts.");
+    let source_counts = row_counts(source_repository).await?;
+    let target_counts = row_counts(target_repository).await?;
+
+    if source_counts != target_counts {
+        return Err(TransferError::RowCountMismatch { source_counts, target_counts });
+    }
+
+    Ok(transferred)
+}
+
+async fn row_counts<R>(repository: &mut R) -> Result<ArchiveSummary, TransferError>
+where
+    R: Repository<BackendError = cipher_database::BackendError>,
+{
+    Ok(ArchiveSummary {
+        users: repository.user_count().await? as usize,
+        profiles: repository.profile_count().await? as usize,
+        staff_roles: repository.staff_role_count().await? as usize,
+    })
+}
+
+#[cfg(all(test, feature = "memory", feature = "sqlite"))]
+mod tests {
+    use cipher_core::repository::RepositoryProvider;
+
+    use super::row_counts;
+    use super::transfer;
+    use super::TransferError;
+    use crate::cli::archive::export_archive;
+    use crate::cli::archive::ArchiveSummary;
+    use crate::cli::testing::contents;
+    use crate::cli::testing::seed;
+    use crate::cli::testing::TempSqlite;
+
+    #[tokio::test]
+    async fn transfer_copies_every_batch() {
+        let source = cipher_database::memory::repository_provider();
+        seed(&mut source.get().await.unwrap(), 5).await;
+
+        let target = TempSqlite::new().await;
+
+        // Two full batches and a partial one.
+        transfer(&source, &target.provider, 2).await.unwrap();
+
+        let target_counts = row_counts(&mut target.provider.get().await.unwrap()).await.unwrap();
+        assert_eq!(target_counts, ArchiveSummary { users: 5, profiles: 10, staff_roles: 2 });
+
+        let source_archive = export_archive(&source).await.unwrap();
+        let target_archive = export_archive(&target.provider).await.unwrap();
+        assert_eq!(contents(&target_archive), contents(&source_archive));
+
+        // Each user keeps their older inactive version and newer active version.
+        for user in &target_archive.users {
+            let versions: Vec<_> = user.profiles.iter()
+                .map(|profile| (profile.trainer_class.clone().unwrap(), profile.is_active))
+                .collect();
+
+            let n = user.discord_user_id - 1000;
+            assert_eq!(versions, vec![(format!("Youngster {}", n), false), (format!("Ace Trainer {}", n), true)]);
+        }
+    }
+
+    #[tokio::test]
+    async fn transfer_copies_batches_of_exactly_the_batch_size() {
+        let source = cipher_database::memory::repository_provider();
+        seed(&mut source.get().await.unwrap(), 4).await;
+
+        let target = TempSqlite::new().await;
+        transfer(&source, &target.provider, 2).await.unwrap();
+
+        let target_counts = row_counts(&mut target.provider.get().await.unwrap()).await.unwrap();
+        assert_eq!(target_counts, ArchiveSummary { users: 4, profiles: 8, staff_roles: 2 });
+    }
+
+    #[tokio::test]
+    async fn transfer_into_non_empty_target_is_rejected() {
+        let source = cipher_database::memory::repository_provider();
+        seed(&mut source.get().await.unwrap(), 2).await;
+
+        let target = TempSqlite::new().await;
+        seed(&mut target.provider.get().await.unwrap(), 1).await;
+
+        let result = transfer(&source, &target.provider, 10).await;
+        assert!(matches!(result, Err(TransferError::TargetNotEmpty)));
+
+        let target_counts = row_counts(&mut target.provider.get().await.unwrap()).await.unwrap();
+        assert_eq!(target_counts, ArchiveSummary { users: 1, profiles: 2, staff_roles: 2 });
+    }
+}
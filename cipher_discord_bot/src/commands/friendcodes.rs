@@ -0,0 +1,129 @@
+use cipher_core::repository::profile_repository::FriendCodeGame;
+use cipher_core::repository::profile_repository::ProfileRepository;
+use cipher_core::repository::RepositoryProvider;
+use poise::ChoiceParameter;
+use poise::CreateReply;
+use serenity::all::CreateEmbed;
+use serenity::all::CreateEmbedFooter;
+
+use crate::app::AppContext;
+use crate::app::AppError;
+use crate::paginator::Paginator;
+
+const ENTRIES_PER_PAGE: usize = 10;
+
+/// Find friend codes shared by members of this server.
+#[poise::command(
+    slash_command,
+    guild_only,
+    subcommands(
+        "list",
+    ),
+)]
+pub async fn friendcodes<R: RepositoryProvider + Send + Sync>(
+    _ctx: AppContext<'_, R, R::BackendError>,
+) -> Result<(), AppError<R::BackendError>> {
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, ChoiceParameter)]
+enum GameChoice {
+    #[name = "Pokémon Go"]
+    PokemonGo,
+    #[name = "Pokémon TCG Pocket"]
+    PokemonPocket,
+    #[name = "Nintendo Switch"]
+    Switch,
+}
+
+impl From<GameChoice> for FriendCodeGame {
+    fn from(value: GameChoice) -> Self {
+        match value {
+            GameChoice::PokemonGo => FriendCodeGame::PokemonGo,
+            GameChoice::PokemonPocket => FriendCodeGame::PokemonPocket,
+            GameChoice::Switch => FriendCodeGame::Switch,
+        }
+    }
+}
+
+/// List the friend codes of every member in this server for a game.
+#[poise::command(
+    slash_command,
+    guild_only,
+)]
+async fn list<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[description = "The game to list friend codes for."] game: GameChoice,
+    #[rename = "page"]
+    #[description = "The page to show. Default is 1."]
+    #[min = 1]
+    option_page_number: Option<usize>,
+    #[description = "Hide reply from other users. Defaults to True."]
+    ephemeral: Option<bool>,
+) -> Result<(), AppError<R::BackendError>> {
+    let guild_id = ctx.guild_id().ok_or(AppError::UnknownCacheOrHttpError)?;
+    let ephemeral = ephemeral.unwrap_or(true);
+
+    // Listing the guild members may take longer than the interaction response deadline.
+    match ephemeral {
+        true => ctx.defer_ephemeral().await?,
+        false => ctx.defer().await?,
+    }
+
+    let colour = crate::utils::bot_color(&ctx).await;
+
+    let mut repo = ctx.data.repository().await?;
+    let profiles = repo.active_profiles_with_code(FriendCodeGame::from(game)).await?;
+
+    // Profiles are not scoped to guilds, so only list members of the current guild.
+    let member_ids = crate::utils::guild_member_ids(&ctx, guild_id).await?;
+
+    let entries: Vec<_> = profiles
+        .iter()
+        .filter(|(discord_user_id, _)| member_ids.contains(discord_user_id))
+        .filter_map(|(discord_user_id, profile)| {
+            profile.friend_code(FriendCodeGame::from(game))
+                .map(|code| format!("<@{}> `{}`", discord_user_id, code))
+        })
+        .collect();
+
+    let title = format!("{} Friend Codes", game.name());
+
+    if entries.is_empty() {
+        let embed = CreateEmbed::new()
+            .title(title)
+            .description(format!("No members of this server have shared a {} friend code yet.", game.name()))
+            .color(colour);
+
+        let reply = CreateReply::default()
+            .embed(embed)
+            .ephemeral(ephemeral);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let max_page_number = entries.len().div_ceil(ENTRIES_PER_PAGE);
+
+    // A public list can be paged by anyone who can see it.
+    let mut paginator = Paginator::new(max_page_number)
+        .page_number(option_page_number.unwrap_or(1))
+        .author_only(ephemeral)
+        .ephemeral(ephemeral);
+
+    paginator.run(ctx, |page_number| {
+        let lower = (page_number - 1) * ENTRIES_PER_PAGE;
+        let upper = (lower + ENTRIES_PER_PAGE).min(entries.len());
+
+        let embed = CreateEmbed::new()
+            .title(&title)
+            .description(entries[lower..upper].join("\n"))
+            .footer(CreateEmbedFooter::new(format!("Page {}/{} · {} members", page_number, max_page_number, entries.len())))
+            .color(colour);
+
+        async move { Ok(embed) }
+    }).await?;
+
+    Ok(())
+}
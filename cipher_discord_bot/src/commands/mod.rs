@@ -3,9 +3,11 @@ use cipher_core::repository::RepositoryProvider;
 use crate::app::AppCommand;
 
 mod about;
+mod friendcodes;
 mod help;
 mod pokeapi;
 mod profile;
+mod staff;
 
 pub fn commands<R>() -> Vec<AppCommand<R, R::BackendError>>
 where
@@ -13,10 +15,12 @@ where
 {
     vec![
         about::about(),
+        friendcodes::friendcodes(),
         help::help(),
         pokeapi::pokeapi(),
         profile::profile(),
         profile::cmu_profile_show(),
+        staff::staff(),
     ]
 }
 
@@ -0,0 +1,125 @@
+use cipher_core::repository::RepositoryProvider;
+use poise::CreateReply;
+use rustemon::model::pokemon::Ability;
+use serenity::all::CreateEmbed;
+use serenity::all::CreateEmbedFooter;
+
+use crate::app::AppContext;
+use crate::app::AppError;
+use crate::pokeapi::display_name;
+use crate::pokeapi::english_effect;
+use crate::pokeapi::english_name;
+use crate::pokeapi::Endpoint;
+
+use super::EntryLookup;
+
+/// The maximum number of Pokémon listed with an ability, keeping the embed field within Discord's limit.
+const MAX_LISTED_POKEMON: usize = 30;
+
+/// Get information about abilities.
+#[poise::command(
+    slash_command,
+    guild_only,
+    subcommands(
+        "list",
+        "search",
+    ),
+)]
+pub async fn ability<R: RepositoryProvider + Send + Sync>(
+    _ctx: AppContext<'_, R, R::BackendError>,
+) -> Result<(), AppError<R::BackendError>> {
+    Ok(())
+}
+
+/// List all of the abilities.
+#[poise::command(slash_command, guild_only)]
+async fn list<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[rename = "page"]
+    #[description = "The page to show. Default is 1."]
+    #[min = 1]
+    option_page_number: Option<usize>,
+    #[rename = "amount"]
+    #[description = "The number of results to show per page. Default is 10."]
+    #[min = 1]
+    #[max = 20]
+    option_amount: Option<usize>,
+) -> Result<(), AppError<R::BackendError>> {
+    super::list_entries(ctx, Endpoint::Ability, "Abilities", option_page_number, option_amount).await
+}
+
+/// Search for an ability by name.
+#[poise::command(slash_command, guild_only)]
+async fn search<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[description = "The name of the ability"]
+    #[autocomplete = "autocomplete_ability_name::<R>"]
+    name: String,
+) -> Result<(), AppError<R::BackendError>> {
+    ctx.defer_ephemeral().await?;
+
+    let pokedex = ctx.data.pokedex();
+
+    let option_ability = match super::find_entry(pokedex, Endpoint::Ability, &name).await {
+        Ok(EntryLookup::Found(entry)) => pokedex.ability(&entry.name).await?,
+        Ok(EntryLookup::NotFound(suggestions)) => return super::send_not_found(ctx, "ability", &name, Ok(suggestions)).await,
+        Err(err) => return super::send_not_found(ctx, "ability", &name, Err(err)).await,
+    };
+
+    let found = match option_ability {
+        Some(found) => found,
+        None => return super::send_not_found(ctx, "ability", &name, Ok(Vec::new())).await,
+    };
+
+    let colour = crate::utils::bot_color(&ctx).await;
+
+    let embed = ability_embed(&found).color(colour);
+
+    ctx.send(CreateReply::default().embed(embed).ephemeral(true)).await?;
+
+    Ok(())
+}
+
+fn ability_embed(found: &Ability) -> CreateEmbed {
+    let title = format!("{} #{}", english_name(&found.names).unwrap_or_else(|| display_name(&found.name)), found.id);
+
+    // Newer abilities often only have flavour text, of which the newest English entry is listed last.
+    let option_description = english_effect(&found.effect_entries)
+        .or_else(|| {
+            found.flavor_text_entries.iter()
+                .rev()
+                .find(|entry| entry.language.name == "en")
+                .map(|entry| super::flatten_text(&entry.flavor_text))
+        });
+
+    let mut pokemon: Vec<_> = found.pokemon.iter()
+        .take(MAX_LISTED_POKEMON)
+        .map(|ability_pokemon| match ability_pokemon.is_hidden {
+            true => format!("{} (Hidden)", display_name(&ability_pokemon.pokemon.name)),
+            false => display_name(&ability_pokemon.pokemon.name),
+        })
+        .collect();
+
+    if found.pokemon.len() > MAX_LISTED_POKEMON {
+        pokemon.push(format!("and {} more", found.pokemon.len() - MAX_LISTED_POKEMON));
+    }
+
+    let mut embed = CreateEmbed::new()
+        .title(title)
+        .footer(CreateEmbedFooter::new(display_name(&found.generation.name)));
+
+    if let Some(description) = option_description {
+        embed = embed.description(description);
+    }
+
+    // Discord rejects embeds with empty field values.
+    if !pokemon.is_empty() {
+        embed = embed.field("Pokémon", pokemon.join(", "), false);
+    }
+
+    embed
+}
+
+async fn autocomplete_ability_name<R: RepositoryProvider + Send + Sync>(ctx: AppContext<'_, R, R::BackendError>, partial: &str) -> Vec<String> {
+    super::autocomplete_entry_name(ctx, Endpoint::Ability, partial).await
+}
@@ -0,0 +1,116 @@
+use cipher_core::repository::RepositoryProvider;
+use poise::CreateReply;
+use rustemon::model::items::Item;
+use serenity::all::CreateEmbed;
+
+use crate::app::AppContext;
+use crate::app::AppError;
+use crate::pokeapi::display_name;
+use crate::pokeapi::english_effect;
+use crate::pokeapi::english_name;
+use crate::pokeapi::Endpoint;
+
+use super::EntryLookup;
+
+/// Get information about items.
+#[poise::command(
+    slash_command,
+    guild_only,
+    subcommands(
+        "list",
+        "search",
+    ),
+)]
+pub async fn item<R: RepositoryProvider + Send + Sync>(
+    _ctx: AppContext<'_, R, R::BackendError>,
+) -> Result<(), AppError<R::BackendError>> {
+    Ok(())
+}
+
+/// List all of the items.
+#[poise::command(slash_command, guild_only)]
+async fn list<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[rename = "page"]
+    #[description = "The page to show. Default is 1."]
+    #[min = 1]
+    option_page_number: Option<usize>,
+    #[rename = "amount"]
+    #[description = "The number of results to show per page. Default is 10."]
+    #[min = 1]
+    #[max = 20]
+    option_amount: Option<usize>,
+) -> Result<(), AppError<R::BackendError>> {
+    super::list_entries(ctx, Endpoint::Item, "Items", option_page_number, option_amount).await
+}
+
+/// Search for an item by name.
+#[poise::command(slash_command, guild_only)]
+async fn search<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[description = "The name of the item"]
+    #[autocomplete = "autocomplete_item_name::<R>"]
+    name: String,
+) -> Result<(), AppError<R::BackendError>> {
+    ctx.defer_ephemeral().await?;
+
+    let pokedex = ctx.data.pokedex();
+
+    let option_item = match super::find_entry(pokedex, Endpoint::Item, &name).await {
+        Ok(EntryLookup::Found(entry)) => pokedex.item(&entry.name).await?,
+        Ok(EntryLookup::NotFound(suggestions)) => return super::send_not_found(ctx, "item", &name, Ok(suggestions)).await,
+        Err(err) => return super::send_not_found(ctx, "item", &name, Err(err)).await,
+    };
+
+    let found = match option_item {
+        Some(found) => found,
+        None => return super::send_not_found(ctx, "item", &name, Ok(Vec::new())).await,
+    };
+
+    let colour = crate::utils::bot_color(&ctx).await;
+
+    let embed = item_embed(&found).color(colour);
+
+    ctx.send(CreateReply::default().embed(embed).ephemeral(true)).await?;
+
+    Ok(())
+}
+
+fn item_embed(found: &Item) -> CreateEmbed {
+    let title = format!("{} #{}", english_name(&found.names).unwrap_or_else(|| display_name(&found.name)), found.id);
+
+    // Newer items often only have flavour text, of which the newest English entry is listed last.
+    let option_description = english_effect(&found.effect_entries)
+        .or_else(|| {
+            found.flavor_text_entries.iter()
+                .rev()
+                .find(|entry| entry.language.name == "en")
+                .map(|entry| super::flatten_text(&entry.text))
+        });
+
+    let mut embed = CreateEmbed::new()
+        .title(title)
+        .field("Category", display_name(&found.category.name), true)
+        .field("Cost", match found.cost {
+            0 => "—".to_string(),
+            cost => format!("₽{}", cost),
+        }, true);
+
+    if let Some(fling_power) = found.fling_power {
+        embed = embed.field("Fling Power", fling_power.to_string(), true);
+    }
+
+    if let Some(description) = option_description {
+        embed = embed.description(description);
+    }
+
+    if let Some(sprite_url) = &found.sprites.default {
+        embed = embed.thumbnail(sprite_url);
+    }
+
+    embed
+}
+
+async fn autocomplete_item_name<R: RepositoryProvider + Send + Sync>(ctx: AppContext<'_, R, R::BackendError>, partial: &str) -> Vec<String> {
+    super::autocomplete_entry_name(ctx, Endpoint::Item, partial).await
+}
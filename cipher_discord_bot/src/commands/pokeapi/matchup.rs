@@ -0,0 +1,323 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use cipher_core::repository::RepositoryProvider;
+use poise::ChoiceParameter;
+use poise::CreateReply;
+use rustemon::model::pokemon::Type;
+use rustemon::model::resource::NamedApiResource;
+use serenity::all::Color;
+use serenity::all::CreateEmbed;
+
+use crate::app::AppContext;
+use crate::app::AppError;
+use crate::pokeapi::display_name;
+use crate::pokeapi::english_name;
+use crate::pokeapi::Endpoint;
+
+use super::pokemon::PokemonLookup;
+use super::EntryLookup;
+
+/// Types that PokéAPI lists but that no Pokémon or move has in the main series games.
+const NON_BATTLE_TYPES: [&str; 3] = ["unknown", "shadow", "stellar"];
+
+/// Damage multipliers are counted in quarters, so combining two types stays exact.
+const NEUTRAL_QUARTERS: u32 = 4;
+
+#[derive(Debug, Clone, Copy, ChoiceParameter)]
+enum MatchupSideChoice {
+    #[name = "Attacking"]
+    Attacking,
+    #[name = "Defending"]
+    Defending,
+}
+
+/// Calculate the damage multipliers of one or two types, or a Pokémon, against every type.
+#[poise::command(slash_command, guild_only)]
+pub async fn matchup<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[rename = "side"]
+    #[description = "Whether the types are attacking or defending. Default is defending."]
+    option_side: Option<MatchupSideChoice>,
+    #[rename = "type"]
+    #[description = "The first type"]
+    #[autocomplete = "autocomplete_type_name::<R>"]
+    option_first_type: Option<String>,
+    #[rename = "second_type"]
+    #[description = "The second type"]
+    #[autocomplete = "autocomplete_type_name::<R>"]
+    option_second_type: Option<String>,
+    #[rename = "pokemon"]
+    #[description = "A Pokémon to use the types of, instead of the type options"]
+    #[autocomplete = "super::pokemon::autocomplete_pokemon_name::<R>"]
+    option_pokemon: Option<String>,
+) -> Result<(), AppError<R::BackendError>> {
+    // Looking up the types of a Pokémon may take longer than the interaction response deadline.
+    ctx.defer_ephemeral().await?;
+
+    let side = option_side.unwrap_or(MatchupSideChoice::Defending);
+    let type_values: Vec<String> = option_first_type.into_iter().chain(option_second_type).collect();
+
+    let validation_error = match (&option_pokemon, type_values.is_empty()) {
+        (Some(_), false) => Some("Provide either types or a Pokémon, not both."),
+        (None, true) => Some("Provide one or two types, or a Pokémon."),
+        _ => None,
+    };
+
+    if let Some(validation_error) = validation_error {
+        let embed = CreateEmbed::new()
+            .title("Validation Error")
+            .description(validation_error)
+            .color(Color::RED);
+
+        ctx.send(CreateReply::default().embed(embed).ephemeral(true)).await?;
+
+        return Ok(());
+    }
+
+    let pokedex = ctx.data.pokedex();
+
+    let (option_pokemon_name, type_names) = match option_pokemon {
+        Some(value) => match super::pokemon::find_pokemon(pokedex, &value).await {
+            Ok(PokemonLookup::Found(found)) => {
+                let mut types = found.types.clone();
+                types.sort_by_key(|pokemon_type| pokemon_type.slot);
+
+                (Some(display_name(&found.name)), types.into_iter().map(|pokemon_type| pokemon_type.type_.name).collect())
+            },
+            Ok(PokemonLookup::NotFound(suggestions)) => return super::send_not_found(ctx, "Pokémon", &value, Ok(suggestions)).await,
+            Err(err) => return super::send_not_found(ctx, "Pokémon", &value, Err(err)).await,
+        },
+        None => {
+            let mut type_names: Vec<String> = Vec::new();
+            for value in &type_values {
+                match super::find_entry(pokedex, Endpoint::Type, value).await {
+                    Ok(EntryLookup::Found(entry)) if !type_names.contains(&entry.name) => type_names.push(entry.name),
+                    Ok(EntryLookup::Found(_)) => {},
+                    Ok(EntryLookup::NotFound(suggestions)) => return super::send_not_found(ctx, "type", value, Ok(suggestions)).await,
+                    Err(err) => return super::send_not_found(ctx, "type", value, Err(err)).await,
+                }
+            }
+
+            (None, type_names)
+        },
+    };
+
+    let mut types = Vec::with_capacity(type_names.len());
+    for type_name in &type_names {
+        match pokedex.pokemon_type(type_name).await? {
+            Some(found) => types.push(found),
+            None => return super::send_not_found(ctx, "type", type_name, Ok(Vec::new())).await,
+        }
+    }
+
+    let other_type_names: Vec<_> = pokedex.entries(Endpoint::Type).await?
+        .iter()
+        .map(|entry| entry.name.clone())
+        .filter(|name| !NON_BATTLE_TYPES.contains(&name.as_str()))
+        .collect();
+
+    // Ordered from the highest multiplier down.
+    let mut multipliers: BTreeMap<Reverse<u32>, Vec<String>> = BTreeMap::new();
+    for other_type_name in &other_type_names {
+        let quarters = match side {
+            MatchupSideChoice::Attacking => attacking_quarters(&types, other_type_name),
+            MatchupSideChoice::Defending => defending_quarters(&types, other_type_name),
+        };
+
+        multipliers.entry(Reverse(quarters)).or_default().push(display_name(other_type_name));
+    }
+
+    let type_list = types.iter()
+        .map(|found| english_name(&found.names).unwrap_or_else(|| display_name(&found.name)))
+        .collect::<Vec<_>>()
+        .join(" / ");
+
+    let subject = match option_pokemon_name {
+        Some(pokemon_name) => format!("{} ({})", pokemon_name, type_list),
+        None => type_list,
+    };
+
+    let (title, description) = match side {
+        MatchupSideChoice::Attacking => (
+            format!("{} Attacking", subject),
+            "The damage multipliers of moves of these types against each defending type. With two types, the more effective one counts.",
+        ),
+        MatchupSideChoice::Defending => (
+            format!("{} Defending", subject),
+            "The damage multipliers of moves of each attacking type against this type combination.",
+        ),
+    };
+
+    let colour = crate::utils::bot_color(&ctx).await;
+
+    let mut embed = CreateEmbed::new()
+        .title(title)
+        .description(description)
+        .color(colour);
+
+    for (Reverse(quarters), type_names) in multipliers {
+        embed = embed.field(format_multiplier(quarters), type_names.join(", "), false);
+    }
+
+    ctx.send(CreateReply::default().embed(embed).ephemeral(true)).await?;
+
+    Ok(())
+}
+
+/// The damage multiplier, in quarters, of a move with one of the attacking types against the defending type,
+/// using whichever attacking type is most effective.
+fn attacking_quarters(attacking_types: &[Type], defending_type_name: &str) -> u32 {
+    attacking_types.iter()
+        .map(|attacking_type| {
+            let relations = &attacking_type.damage_relations;
+            relation_quarters(&relations.double_damage_to, &relations.half_damage_to, &relations.no_damage_to, defending_type_name)
+        })
+        .max()
+        .unwrap_or(NEUTRAL_QUARTERS)
+}
+
+/// The damage multiplier, in quarters, of a move of the attacking type against all of the defending types combined.
+fn defending_quarters(defending_types: &[Type], attacking_type_name: &str) -> u32 {
+    defending_types.iter()
+        .fold(NEUTRAL_QUARTERS, |quarters, defending_type| {
+            let relations = &defending_type.damage_relations;
+            let factor = relation_quarters(&relations.double_damage_from, &relations.half_damage_from, &relations.no_damage_from, attacking_type_name);
+
+            quarters * factor / NEUTRAL_QUARTERS
+        })
+}
+
+fn relation_quarters(
+    double_damage: &[NamedApiResource<Type>],
+    half_damage: &[NamedApiResource<Type>],
+    no_damage: &[NamedApiResource<Type>],
+    type_name: &str,
+) -> u32 {
+    let contains = |types: &[NamedApiResource<Type>]| types.iter().any(|other| other.name == type_name);
+
+    if contains(no_damage) {
+        0
+    } else if contains(half_damage) {
+        NEUTRAL_QUARTERS / 2
+    } else if contains(double_damage) {
+        NEUTRAL_QUARTERS * 2
+    } else {
+        NEUTRAL_QUARTERS
+    }
+}
+
+fn format_multiplier(quarters: u32) -> String {
+    match quarters {
+        0 => "0×".to_string(),
+        1 => "¼×".to_string(),
+        2 => "½×".to_string(),
+        quarters => format!("{}×", quarters / NEUTRAL_QUARTERS),
+    }
+}
+
+/// Suggests types that Pokémon and moves can have, leaving out the types listed in [`NON_BATTLE_TYPES`].
+pub(super) async fn autocomplete_type_name<R: RepositoryProvider + Send + Sync>(ctx: AppContext<'_, R, R::BackendError>, partial: &str) -> Vec<String> {
+    let mut choices = super::autocomplete_entry_name(ctx, Endpoint::Type, partial).await;
+    choices.retain(|choice| !NON_BATTLE_TYPES.contains(&crate::pokeapi::pokeapi_name(choice).as_str()));
+    choices
+}
+
+#[cfg(test)]
+mod tests {
+    use rustemon::model::pokemon::Type;
+    use rustemon::model::resource::NamedApiResource;
+
+    use super::attacking_quarters;
+    use super::defending_quarters;
+    use super::format_multiplier;
+
+    fn resources(names: &[&str]) -> Vec<NamedApiResource<Type>> {
+        names.iter()
+            .map(|name| {
+                let mut resource = NamedApiResource::default();
+                resource.name = name.to_string();
+                resource
+            })
+            .collect()
+    }
+
+    /// A type with only the relations used when it is defending.
+    fn defending_type(double_damage_from: &[&str], half_damage_from: &[&str], no_damage_from: &[&str]) -> Type {
+        let mut pokemon_type = Type::default();
+        pokemon_type.damage_relations.double_damage_from = resources(double_damage_from);
+        pokemon_type.damage_relations.half_damage_from = resources(half_damage_from);
+        pokemon_type.damage_relations.no_damage_from = resources(no_damage_from);
+        pokemon_type
+    }
+
+    /// A type with only the relations used when it is attacking.
+    fn attacking_type(double_damage_to: &[&str], half_damage_to: &[&str], no_damage_to: &[&str]) -> Type {
+        let mut pokemon_type = Type::default();
+        pokemon_type.damage_relations.double_damage_to = resources(double_damage_to);
+        pokemon_type.damage_relations.half_damage_to = resources(half_damage_to);
+        pokemon_type.damage_relations.no_damage_to = resources(no_damage_to);
+        pokemon_type
+    }
+
+    fn grass() -> Type {
+        defending_type(&["fire", "ice", "poison", "flying", "bug"], &["water", "electric", "grass", "ground"], &[])
+    }
+
+    fn poison() -> Type {
+        defending_type(&["ground", "psychic"], &["fighting", "poison", "bug", "grass", "fairy"], &[])
+    }
+
+    fn ground() -> Type {
+        defending_type(&["water", "grass", "ice"], &["poison", "rock"], &["electric"])
+    }
+
+    fn flying() -> Type {
+        defending_type(&["electric", "ice", "rock"], &["grass", "fighting", "bug"], &["ground"])
+    }
+
+    #[test]
+    fn defending_quarters_multiplies_both_types() {
+        // Grass / Poison
+        assert_eq!(defending_quarters(&[grass(), poison()], "grass"), 1);
+        assert_eq!(defending_quarters(&[grass(), poison()], "fire"), 8);
+        assert_eq!(defending_quarters(&[grass(), poison()], "water"), 2);
+        assert_eq!(defending_quarters(&[grass(), poison()], "normal"), 4);
+
+        // Ground / Flying
+        assert_eq!(defending_quarters(&[ground(), flying()], "ice"), 16);
+    }
+
+    #[test]
+    fn defending_quarters_keeps_immunities_of_either_type() {
+        // Flying is weak to electric, but ground is immune to it.
+        assert_eq!(defending_quarters(&[ground(), flying()], "electric"), 0);
+        // Ground takes neutral damage from ground, but flying is immune to it.
+        assert_eq!(defending_quarters(&[ground(), flying()], "ground"), 0);
+    }
+
+    #[test]
+    fn attacking_quarters_uses_the_most_effective_type() {
+        let electric = attacking_type(&["water", "flying"], &["electric", "grass", "dragon"], &["ground"]);
+        let ground = attacking_type(&["fire", "electric", "poison", "rock", "steel"], &["grass", "bug"], &["flying"]);
+
+        let both = [electric, ground];
+
+        assert_eq!(attacking_quarters(&both[..1], "ground"), 0);
+        assert_eq!(attacking_quarters(&both[1..], "flying"), 0);
+        assert_eq!(attacking_quarters(&both, "flying"), 8);
+        assert_eq!(attacking_quarters(&both, "ground"), 4);
+        assert_eq!(attacking_quarters(&both, "grass"), 2);
+        assert_eq!(attacking_quarters(&[], "grass"), 4);
+    }
+
+    #[test]
+    fn format_multiplier_shows_fractions() {
+        assert_eq!(format_multiplier(0), "0×");
+        assert_eq!(format_multiplier(1), "¼×");
+        assert_eq!(format_multiplier(2), "½×");
+        assert_eq!(format_multiplier(4), "1×");
+        assert_eq!(format_multiplier(8), "2×");
+        assert_eq!(format_multiplier(16), "4×");
+    }
+}
@@ -1,9 +1,27 @@
 use cipher_core::repository::RepositoryProvider;
+use poise::CreateReply;
+use serenity::all::CreateEmbed;
+use serenity::all::CreateEmbedFooter;
 
 use crate::app::AppContext;
 use crate::app::AppError;
+use crate::paginator::Paginator;
+use crate::pokeapi::display_name;
+use crate::pokeapi::pokeapi_name;
+use crate::pokeapi::Endpoint;
+use crate::pokeapi::PokedexEntry;
+use crate::pokeapi::PokedexError;
+use crate::pokeapi::PokedexSource;
 
+mod ability;
+mod item;
+mod matchup;
+mod moves;
 mod pokemon;
+mod types;
+
+/// The maximum number of choices Discord accepts in an autocomplete response.
+const AUTOCOMPLETE_CHOICE_LIMIT: usize = 25;
 
 /// Query PokéAPI for Pokémon related information.
 #[poise::command(
@@ -11,6 +29,11 @@ mod pokemon;
     guild_only,
     subcommands(
         "pokemon::pokemon",
+        "moves::move_",
+        "ability::ability",
+        "item::item",
+        "types::type_",
+        "matchup::matchup",
     ),
 )]
 pub async fn pokeapi<R: RepositoryProvider + Send + Sync>(
@@ -18,3 +41,151 @@ pub async fn pokeapi<R: RepositoryProvider + Send + Sync>(
 ) -> Result<(), AppError<R::BackendError>> {
     Ok(())
 }
+
+/// Pages through the names and ids of every resource of an endpoint.
+async fn list_entries<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    endpoint: Endpoint,
+    title: &str,
+    option_page_number: Option<usize>,
+    option_amount: Option<usize>,
+) -> Result<(), AppError<R::BackendError>> {
+    // Fetching an uncached resource list may take longer than the interaction response deadline.
+    ctx.defer_ephemeral().await?;
+
+    let colour = crate::utils::bot_color(&ctx).await;
+    let entries = ctx.data.pokedex().entries(endpoint).await?;
+
+    if entries.is_empty() {
+        let embed = CreateEmbed::new()
+            .title(title)
+            .description("The Pokédex has no entries here.")
+            .color(colour);
+
+        ctx.send(CreateReply::default().embed(embed).ephemeral(true)).await?;
+
+        return Ok(());
+    }
+
+    let amount = option_amount.unwrap_or(10);
+    let max_page_number = entries.len().div_ceil(amount);
+
+    let mut paginator = Paginator::new(max_page_number).page_number(option_page_number.unwrap_or(1));
+
+    paginator.run(ctx, |page_number| {
+        let lower = (page_number - 1) * amount;
+        let upper = (lower + amount).min(entries.len());
+
+        let embed_description = entries[lower..upper].iter()
+            .map(|entry| format!("{} #{}", display_name(&entry.name), entry.id))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let embed = CreateEmbed::new()
+            .title(title)
+            .description(embed_description)
+            .footer(CreateEmbedFooter::new(format!("Page {}/{} · {} entries", page_number, max_page_number, entries.len())))
+            .color(colour);
+
+        async move { Ok(embed) }
+    }).await?;
+
+    Ok(())
+}
+
+enum EntryLookup {
+    Found(PokedexEntry),
+    /// The most similar names, for suggesting to the user.
+    NotFound(Vec<String>),
+}
+
+/// Finds a resource of an endpoint by a user provided name or id.
+async fn find_entry(pokedex: &dyn PokedexSource, endpoint: Endpoint, value: &str) -> Result<EntryLookup, PokedexError> {
+    let name = pokeapi_name(value);
+    let entries = pokedex.entries(endpoint).await?;
+
+    let option_entry = match name.parse::<i64>() {
+        Ok(id) => entries.iter().find(|entry| entry.id == id),
+        Err(_) => entries.iter().find(|entry| entry.name == name),
+    };
+
+    if let Some(entry) = option_entry {
+        return Ok(EntryLookup::Found(entry.clone()));
+    }
+
+    let suggestions = crate::utils::closest_matches(&name, entries.iter().map(|entry| entry.name.as_str()), 3)
+        .into_iter()
+        .map(display_name)
+        .collect();
+
+    Ok(EntryLookup::NotFound(suggestions))
+}
+
+/// Tells the user that nothing of the given kind is named `value`, suggesting similar names if there are any.
+async fn send_not_found<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    kind: &str,
+    value: &str,
+    suggestions: Result<Vec<String>, PokedexError>,
+) -> Result<(), AppError<R::BackendError>> {
+    let description = match suggestions {
+        Ok(suggestions) => match suggestions.as_slice() {
+            [] => format!("No {} is named `{}`.", kind, value),
+            [suggestion] => format!("No {} is named `{}`. Did you mean {}?", kind, value, suggestion),
+            [suggestions @ .., last] => format!("No {} is named `{}`. Did you mean {} or {}?", kind, value, suggestions.join(", "), last),
+        },
+        Err(err) => {
+            log::error!("{}", err);
+            "A network error has occurred, please try again later.".to_string()
+        },
+    };
+
+    let colour = crate::utils::bot_color(&ctx).await;
+
+    let embed = CreateEmbed::new()
+        .title(format!("Could not find requested {}", kind))
+        .description(description)
+        .color(colour);
+
+    let reply = CreateReply::default().embed(embed).ephemeral(true);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+/// Suggests resources of an endpoint whose name starts with or contains the partial input,
+/// falling back to the most similar names if there are none.
+async fn autocomplete_entry_name<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    endpoint: Endpoint,
+    partial: &str,
+) -> Vec<String> {
+    let entries = match ctx.data.pokedex().entries(endpoint).await {
+        Ok(entries) => entries,
+        Err(err) => {
+            log::warn!("Failed to list {} entries for autocomplete: {}", endpoint.path(), err);
+            return Vec::new();
+        },
+    };
+
+    let name = pokeapi_name(partial);
+    let names = entries.iter().map(|entry| entry.name.as_str());
+
+    let mut choices: Vec<_> = names.clone().filter(|candidate| candidate.starts_with(&name)).collect();
+    choices.extend(names.clone().filter(|candidate| !candidate.starts_with(&name) && candidate.contains(&name)));
+
+    if choices.is_empty() {
+        choices = crate::utils::closest_matches(&name, names, AUTOCOMPLETE_CHOICE_LIMIT);
+    }
+
+    choices.into_iter()
+        .take(AUTOCOMPLETE_CHOICE_LIMIT)
+        .map(display_name)
+        .collect()
+}
+
+/// Joins the lines of game text, which contain the line breaks of the game text box.
+fn flatten_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,116 @@
+use cipher_core::repository::RepositoryProvider;
+use poise::CreateReply;
+use rustemon::model::moves::Move;
+use serenity::all::CreateEmbed;
+use serenity::all::CreateEmbedFooter;
+
+use crate::app::AppContext;
+use crate::app::AppError;
+use crate::pokeapi::display_name;
+use crate::pokeapi::english_effect;
+use crate::pokeapi::english_name;
+use crate::pokeapi::Endpoint;
+
+use super::EntryLookup;
+
+/// Get information about moves.
+#[poise::command(
+    slash_command,
+    guild_only,
+    rename = "move",
+    subcommands(
+        "list",
+        "search",
+    ),
+)]
+pub async fn move_<R: RepositoryProvider + Send + Sync>(
+    _ctx: AppContext<'_, R, R::BackendError>,
+) -> Result<(), AppError<R::BackendError>> {
+    Ok(())
+}
+
+/// List all of the moves.
+#[poise::command(slash_command, guild_only)]
+async fn list<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[rename = "page"]
+    #[description = "The page to show. Default is 1."]
+    #[min = 1]
+    option_page_number: Option<usize>,
+    #[rename = "amount"]
+    #[description = "The number of results to show per page. Default is 10."]
+    #[min = 1]
+    #[max = 20]
+    option_amount: Option<usize>,
+) -> Result<(), AppError<R::BackendError>> {
+    super::list_entries(ctx, Endpoint::Move, "Moves", option_page_number, option_amount).await
+}
+
+/// Search for a move by name.
+#[poise::command(slash_command, guild_only)]
+async fn search<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[description = "The name of the move"]
+    #[autocomplete = "autocomplete_move_name::<R>"]
+    name: String,
+) -> Result<(), AppError<R::BackendError>> {
+    ctx.defer_ephemeral().await?;
+
+    let pokedex = ctx.data.pokedex();
+
+    let option_move = match super::find_entry(pokedex, Endpoint::Move, &name).await {
+        Ok(EntryLookup::Found(entry)) => pokedex.pokemon_move(&entry.name).await?,
+        Ok(EntryLookup::NotFound(suggestions)) => return super::send_not_found(ctx, "move", &name, Ok(suggestions)).await,
+        Err(err) => return super::send_not_found(ctx, "move", &name, Err(err)).await,
+    };
+
+    let found = match option_move {
+        Some(found) => found,
+        None => return super::send_not_found(ctx, "move", &name, Ok(Vec::new())).await,
+    };
+
+    let colour = crate::utils::bot_color(&ctx).await;
+
+    let embed = move_embed(&found).color(colour);
+
+    ctx.send(CreateReply::default().embed(embed).ephemeral(true)).await?;
+
+    Ok(())
+}
+
+fn move_embed(found: &Move) -> CreateEmbed {
+    let title = format!("{} #{}", english_name(&found.names).unwrap_or_else(|| display_name(&found.name)), found.id);
+
+    // Newer moves often only have flavour text, of which the newest English entry is listed last.
+    let option_description = english_effect(&found.effect_entries)
+        .map(|effect| match found.effect_chance {
+            Some(effect_chance) => effect.replace("$effect_chance", &effect_chance.to_string()),
+            None => effect,
+        })
+        .or_else(|| {
+            found.flavor_text_entries.iter()
+                .rev()
+                .find(|entry| entry.language.name == "en")
+                .map(|entry| super::flatten_text(&entry.flavor_text))
+        });
+
+    let mut embed = CreateEmbed::new()
+        .title(title)
+        .field("Type", display_name(&found.type_.name), true)
+        .field("Damage Class", display_name(&found.damage_class.name), true)
+        .field("Power", found.power.map(|power| power.to_string()).unwrap_or_else(|| "—".to_string()), true)
+        .field("Accuracy", found.accuracy.map(|accuracy| format!("{}%", accuracy)).unwrap_or_else(|| "—".to_string()), true)
+        .field("PP", found.pp.map(|pp| pp.to_string()).unwrap_or_else(|| "—".to_string()), true)
+        .field("Priority", format!("{:+}", found.priority), true)
+        .footer(CreateEmbedFooter::new(display_name(&found.generation.name)));
+
+    if let Some(description) = option_description {
+        embed = embed.description(description);
+    }
+
+    embed
+}
+
+async fn autocomplete_move_name<R: RepositoryProvider + Send + Sync>(ctx: AppContext<'_, R, R::BackendError>, partial: &str) -> Vec<String> {
+    super::autocomplete_entry_name(ctx, Endpoint::Move, partial).await
+}
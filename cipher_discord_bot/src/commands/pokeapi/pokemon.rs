@@ -1,17 +1,26 @@
-use std::time::Duration;
+use std::collections::HashSet;
 
 use cipher_core::repository::RepositoryProvider;
 use poise::CreateReply;
-use rustemon::Follow;
-use serenity::all::ComponentInteractionCollector;
-use serenity::all::CreateActionRow;
-use serenity::all::CreateButton;
+use rustemon::model::evolution::ChainLink;
+use rustemon::model::evolution::EvolutionChain;
+use rustemon::model::evolution::EvolutionDetail;
+use rustemon::model::pokemon::Pokemon;
+use rustemon::model::pokemon::PokemonSpecies;
 use serenity::all::CreateEmbed;
-use serenity::all::CreateInteractionResponse;
-use uuid::Uuid;
+use serenity::all::CreateEmbedFooter;
 
 use crate::app::AppContext;
 use crate::app::AppError;
+use crate::paginator::Paginator;
+use crate::pokeapi::display_name;
+use crate::pokeapi::english_name;
+use crate::pokeapi::pokeapi_name;
+use crate::pokeapi::resource_id;
+use crate::pokeapi::Endpoint;
+use crate::pokeapi::PokedexEntry;
+use crate::pokeapi::PokedexError;
+use crate::pokeapi::PokedexSource;
 
 /// Get information about Pokémon.
 #[poise::command(
@@ -42,139 +51,353 @@ async fn list<R: RepositoryProvider + Send + Sync>(
     #[max = 20]
     option_amount: Option<usize>,
 ) -> Result<(), AppError<R::BackendError>> {
+    ctx.defer_ephemeral().await?;
+
     let colour = crate::utils::bot_color(&ctx).await;
-    let working_embed = CreateEmbed::new()
-        .title("Consulting the Pokédex")
-        .description("Just a moment...")
-        .color(colour);
+    let pokedex = ctx.data.pokedex();
 
-    let working_reply = CreateReply::default()
-        .embed(working_embed)
-        .components(vec![])
-        .ephemeral(true);
+    // The index is read once, only the Pokémon on the shown page are looked up.
+    let entries = pokedex.entries(Endpoint::Pokemon).await?;
 
-    let reply_handle = ctx.send(working_reply.clone()).await?;
+    if entries.is_empty() {
+        let embed = CreateEmbed::new()
+            .title("Pokémon")
+            .description("The Pokédex has no entries here.")
+            .color(colour);
 
-    let rustemon_client = rustemon::client::RustemonClient::default();
-    let all = rustemon::pokemon::pokemon::get_all_entries(&rustemon_client).await?;
+        ctx.send(CreateReply::default().embed(embed).ephemeral(true)).await?;
+
+        return Ok(());
+    }
 
     let amount = option_amount.unwrap_or(10);
-    let max_page_number = (all.len() + amount - 1) / amount;
-    let mut page_number = option_page_number.unwrap_or(1).min(max_page_number);
-
-    loop {
-        let page_index = page_number - 1;
-        let lower = page_index * amount;
-        let upper = (lower + amount).min(all.len());
-
-        let mut embed_description = String::new();
-        for pokemon in &all[lower..upper] {
-            let pokemon = pokemon.follow(&rustemon_client).await?;
-            embed_description.push_str(&format!("{} #{}\n", pokemon.name, pokemon.id));
+    let max_page_number = entries.len().div_ceil(amount);
+
+    // The current page stays visible while the next one loads, with its components disabled.
+    let mut paginator = Paginator::new(max_page_number)
+        .page_number(option_page_number.unwrap_or(1))
+        .disable_while_rendering(true);
+
+    paginator.run(ctx, |page_number| {
+        let lower = (page_number - 1) * amount;
+        let upper = (lower + amount).min(entries.len());
+        let page_entries = &entries[lower..upper];
+        let entry_count = entries.len();
+
+        async move {
+            let embed = CreateEmbed::new()
+                .title("Pokémon")
+                .description(pokemon_list_page(pokedex, page_entries).await?)
+                .footer(CreateEmbedFooter::new(format!("Page {}/{} · {} Pokémon", page_number, max_page_number, entry_count)))
+                .color(colour);
+
+            Ok(embed)
         }
-        embed_description.pop();
+    }).await?;
 
-        let embed = CreateEmbed::new()
-            .title(format!("Pokémon Page {}/{}", page_number, max_page_number))
-            .description(embed_description)
-            .color(colour);
+    Ok(())
+}
 
-        let previous_button_id = Uuid::new_v4().to_string();
-        let previous_button = CreateButton::new(&previous_button_id)
-            .label("Previous")
-            .disabled(page_index <= 0);
+/// Lists the name, Pokédex number and types of each Pokémon on a page, looking them up concurrently.
+async fn pokemon_list_page(pokedex: &dyn PokedexSource, entries: &[PokedexEntry]) -> Result<String, PokedexError> {
+    let found = futures::future::try_join_all(entries.iter().map(|entry| pokedex.pokemon(&entry.name))).await?;
+
+    let lines: Vec<_> = entries.iter()
+        .zip(found)
+        .map(|(entry, option_pokemon)| match option_pokemon {
+            Some(pokemon) => {
+                let mut types = pokemon.types;
+                types.sort_by_key(|pokemon_type| pokemon_type.slot);
+
+                let types = types.iter()
+                    .map(|pokemon_type| display_name(&pokemon_type.type_.name))
+                    .collect::<Vec<_>>()
+                    .join(" / ");
+
+                format!("{} #{} · {}", display_name(&entry.name), entry.id, types)
+            },
+            None => format!("{} #{}", display_name(&entry.name), entry.id),
+        })
+        .collect();
+
+    Ok(lines.join("\n"))
+}
 
-        let next_button_id = Uuid::new_v4().to_string();
-        let next_button = CreateButton::new(&next_button_id)
-            .label("Next")
-            .disabled(page_number >= max_page_number);
+/// Search for a Pokémon by name or Pokédex number.
+#[poise::command(slash_command, guild_only)]
+async fn search<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[description = "The name or Pokédex number of the Pokémon"]
+    #[autocomplete = "autocomplete_pokemon_name::<R>"]
+    name: String,
+) -> Result<(), AppError<R::BackendError>> {
+    // Looking up the species, form and evolution chain may take longer than the interaction response deadline.
+    ctx.defer_ephemeral().await?;
 
-        let action_row = CreateActionRow::Buttons(vec![previous_button, next_button]);
+    match find_pokemon(ctx.data.pokedex(), &name).await {
+        Ok(PokemonLookup::Found(found)) => show_pokemon(ctx, *found).await,
+        Ok(PokemonLookup::NotFound(suggestions)) => super::send_not_found(ctx, "Pokémon", &name, Ok(suggestions)).await,
+        Err(err) => super::send_not_found(ctx, "Pokémon", &name, Err(err)).await,
+    }
+}
 
-        let reply = CreateReply::default()
-            .embed(embed)
-            .components(vec![action_row])
-            .ephemeral(true);
+/// Shows the Pokédex entry of a Pokémon, split into overview, base stat and evolution pages.
+async fn show_pokemon<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    found: Pokemon,
+) -> Result<(), AppError<R::BackendError>> {
+    let colour = crate::utils::bot_color(&ctx).await;
+    let pokedex = ctx.data.pokedex();
 
-        reply_handle.edit(ctx.into(), reply).await?;
+    let option_species = pokedex.pokemon_species(&found.species.name).await?;
 
-        let collector = ComponentInteractionCollector::new(ctx)
-            .author_id(ctx.author().id)
-            .channel_id(ctx.channel_id())
-            .timeout(Duration::from_secs(60));
+    let option_evolution_chain = match option_species.as_ref().and_then(|species| species.evolution_chain.as_ref()) {
+        Some(evolution_chain) => match resource_id(&evolution_chain.url) {
+            Some(id) => pokedex.evolution_chain(id).await?,
+            None => None,
+        },
+        None => None,
+    };
 
-        let mci = match collector.await {
-            Some(mci) => mci,
-            None => break,
-        };
+    let title = match &option_species {
+        Some(species) => format!(
+            "{} #{}",
+            english_name(&species.names).unwrap_or_else(|| display_name(&species.name)),
+            species.id,
+        ),
+        None => format!("{} #{}", display_name(&found.name), found.id),
+    };
+
+    let sprite_url = found.sprites.other.official_artwork.front_default.clone()
+        .or(found.sprites.front_default.clone());
+
+    let pages = [
+        ("Overview", overview_page(&found, option_species.as_ref())),
+        ("Base Stats", base_stats_page(&found)),
+        ("Evolution", evolution_page(&found, option_evolution_chain.as_ref())),
+    ];
+
+    let max_page_number = pages.len();
+    let page_labels = pages.iter().map(|(page_name, _)| page_name.to_string()).collect();
+
+    let mut paginator = Paginator::new(max_page_number).page_labels(page_labels);
+
+    paginator.run(ctx, |page_number| {
+        let (page_name, page) = &pages[page_number - 1];
+
+        let mut embed = page.clone()
+            .title(&title)
+            .footer(CreateEmbedFooter::new(format!("Page {}/{} · {}", page_number, max_page_number, page_name)))
+            .color(colour);
+
+        if let Some(sprite_url) = &sprite_url {
+            embed = embed.thumbnail(sprite_url);
+        }
 
-        if mci.data.custom_id == previous_button_id {
-            page_number -= 1;
+        async move { Ok(embed) }
+    }).await?;
+
+    Ok(())
+}
+
+fn overview_page(pokemon: &Pokemon, option_species: Option<&PokemonSpecies>) -> CreateEmbed {
+    let mut embed = CreateEmbed::new();
+
+    if let Some(species) = option_species {
+        // The newest English entry is listed last.
+        let option_flavor_text = species.flavor_text_entries.iter()
+            .rev()
+            .find(|entry| entry.language.name == "en")
+            .map(|entry| super::flatten_text(&entry.flavor_text));
+
+        if let Some(flavor_text) = option_flavor_text {
+            embed = embed.description(flavor_text);
         }
 
-        if mci.data.custom_id == next_button_id {
-            page_number += 1;
+        if let Some(genus) = species.genera.iter().find(|genus| genus.language.name == "en") {
+            embed = embed.field("Category", &genus.genus, true);
         }
+    }
+
+    let mut types = pokemon.types.clone();
+    types.sort_by_key(|pokemon_type| pokemon_type.slot);
+
+    let types = types.iter()
+        .map(|pokemon_type| display_name(&pokemon_type.type_.name))
+        .collect::<Vec<_>>()
+        .join(" / ");
+
+    let mut abilities = pokemon.abilities.clone();
+    abilities.sort_by_key(|ability| ability.slot);
+
+    let abilities = abilities.iter()
+        .map(|ability| match ability.is_hidden {
+            true => format!("{} (Hidden)", display_name(&ability.ability.name)),
+            false => display_name(&ability.ability.name),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
 
-        mci.create_response(ctx, CreateInteractionResponse::Acknowledge).await?;
+    // Height is measured in decimetres and weight in hectograms.
+    embed = embed
+        .field("Types", types, true)
+        .field("Height", format!("{:.1} m", pokemon.height as f64 / 10.0), true)
+        .field("Weight", format!("{:.1} kg", pokemon.weight as f64 / 10.0), true);
 
-        reply_handle.edit(ctx.into(), working_reply.clone()).await?;
+    if !abilities.is_empty() {
+        embed = embed.field("Abilities", abilities, false);
     }
 
-    reply_handle.delete(ctx.into()).await?;
+    embed
+}
+
+fn base_stats_page(pokemon: &Pokemon) -> CreateEmbed {
+    let mut lines = Vec::new();
 
-    Ok(())
+    for stat in &pokemon.stats {
+        let stat_name = match stat.stat.name.as_str() {
+            "hp" => "HP".to_string(),
+            "special-attack" => "Sp. Atk".to_string(),
+            "special-defense" => "Sp. Def".to_string(),
+            name => display_name(name),
+        };
+
+        lines.push(format!("`{:<7} {:>3}` {}", stat_name, stat.base_stat, stat_bar(stat.base_stat)));
+    }
+
+    let total: i64 = pokemon.stats.iter().map(|stat| stat.base_stat).sum();
+    lines.push(format!("`{:<7} {:>3}`", "Total", total));
+
+    CreateEmbed::new().description(lines.join("\n"))
 }
 
-/// Search for a Pokémon by name.
-#[poise::command(slash_command, guild_only)]
-async fn search<R: RepositoryProvider + Send + Sync>(
-    ctx: AppContext<'_, R, R::BackendError>,
-    #[description = "The name of the Pokémon"] name: String,
-) -> Result<(), AppError<R::BackendError>> {
-    let rustemon_client = rustemon::client::RustemonClient::default();
+/// Draws a base stat as a bar of up to 15 blocks, where a full bar is a base stat of 255.
+fn stat_bar(base_stat: i64) -> String {
+    let blocks = (base_stat.clamp(0, 255) as usize * 15).div_ceil(255);
+    "█".repeat(blocks)
+}
 
-    let colour = crate::utils::bot_color(&ctx).await;
+fn evolution_page(pokemon: &Pokemon, option_evolution_chain: Option<&EvolutionChain>) -> CreateEmbed {
+    let evolution_chain = match option_evolution_chain {
+        Some(evolution_chain) => evolution_chain,
+        None => return CreateEmbed::new().description("No evolution data is available for this Pokémon."),
+    };
 
-    let found = match rustemon::pokemon::pokemon::get_by_name(&name, &rustemon_client).await {
-        Ok(found) => found,
-        Err(err) => {
-            log::error!("{}", err);
+    let mut lines = Vec::new();
+    evolution_lines(&evolution_chain.chain, &pokemon.species.name, 0, &mut lines);
 
-            let embed = CreateEmbed::new()
-                .title("Could not find requested Pokémon")
-                .description(
-                    "Either no Pokémon exists with that name or a network error has occurred.",
-                )
-                .color(colour);
+    if lines.len() <= 1 {
+        lines.push("This Pokémon does not evolve.".to_string());
+    }
 
-            let reply = CreateReply::default().embed(embed).ephemeral(true);
+    CreateEmbed::new().description(lines.join("\n"))
+}
 
-            ctx.send(reply).await?;
+/// Lists a species and everything it evolves into, indenting each stage and highlighting the current species.
+fn evolution_lines(link: &ChainLink, current_species_name: &str, depth: usize, lines: &mut Vec<String>) {
+    let mut name = display_name(&link.species.name);
+    if link.species.name == current_species_name {
+        name = format!("**{}**", name);
+    }
 
-            return Ok(());
-        }
+    let line = match (depth, link.evolution_details.first()) {
+        (0, _) => name,
+        (_, Some(evolution_detail)) => format!("{}↳ {} ({})", "\u{2003}".repeat(depth - 1), name, evolution_condition(evolution_detail)),
+        (_, None) => format!("{}↳ {}", "\u{2003}".repeat(depth - 1), name),
     };
 
-    let forms = found
-        .forms
-        .iter()
-        .map(|f| f.name.clone())
-        .collect::<Vec<_>>()
-        .join(", ");
+    lines.push(line);
 
-    let mut embed = CreateEmbed::new()
-        .title(format!("{} #{}", found.name, found.id))
-        .field("Forms", forms, false)
-        .color(colour);
+    for evolution in &link.evolves_to {
+        evolution_lines(evolution, current_species_name, depth + 1, lines);
+    }
+}
 
-    if let Some(sprite) = found.sprites.front_default {
-        embed = embed.thumbnail(sprite);
+/// Describes what causes an evolution, e.g. `Level 16` or `Use Thunder Stone`.
+fn evolution_condition(evolution_detail: &EvolutionDetail) -> String {
+    let mut conditions = Vec::new();
+
+    match (evolution_detail.trigger.name.as_str(), evolution_detail.min_level, &evolution_detail.item) {
+        ("level-up", Some(min_level), _) => conditions.push(format!("Level {}", min_level)),
+        ("use-item", _, Some(item)) => conditions.push(format!("Use {}", display_name(&item.name))),
+        (trigger, _, _) => conditions.push(display_name(trigger)),
+    }
+
+    if let Some(held_item) = &evolution_detail.held_item {
+        conditions.push(format!("holding {}", display_name(&held_item.name)));
+    }
+    if let Some(known_move) = &evolution_detail.known_move {
+        conditions.push(format!("knowing {}", display_name(&known_move.name)));
+    }
+    if evolution_detail.min_happiness.is_some() {
+        conditions.push("with high friendship".to_string());
+    }
+    if evolution_detail.min_affection.is_some() {
+        conditions.push("with high affection".to_string());
     }
+    if !evolution_detail.time_of_day.is_empty() {
+        conditions.push(format!("during the {}", evolution_detail.time_of_day));
+    }
+
+    conditions.join(", ")
+}
 
-    let reply = CreateReply::default().embed(embed).ephemeral(true);
+pub(super) enum PokemonLookup {
+    Found(Box<Pokemon>),
+    /// No Pokémon matched, with the names of up to three similarly named Pokémon.
+    NotFound(Vec<String>),
+}
 
-    ctx.send(reply).await?;
+/// Finds a Pokémon by a user provided species name, form name or Pokédex number.
+///
+/// Species resolve to their default form, e.g. `Deoxys` to `deoxys-normal`.
+pub(super) async fn find_pokemon(pokedex: &dyn PokedexSource, value: &str) -> Result<PokemonLookup, PokedexError> {
+    let name = pokeapi_name(value);
+    let species_entries = pokedex.entries(Endpoint::PokemonSpecies).await?;
 
-    Ok(())
+    let option_species_entry = match name.parse::<i64>() {
+        Ok(dex_number) => species_entries.iter().find(|entry| entry.id == dex_number),
+        Err(_) => species_entries.iter().find(|entry| entry.name == name),
+    };
+
+    if let Some(species_entry) = option_species_entry {
+        if let Some(species) = pokedex.pokemon_species(&species_entry.name).await? {
+            let option_variety = species.varieties.iter()
+                .find(|variety| variety.is_default)
+                .or(species.varieties.first());
+
+            if let Some(variety) = option_variety {
+                if let Some(pokemon) = pokedex.pokemon(&variety.pokemon.name).await? {
+                    return Ok(PokemonLookup::Found(Box::new(pokemon)));
+                }
+            }
+        }
+    }
+
+    let pokemon_entries = pokedex.entries(Endpoint::Pokemon).await?;
+
+    if pokemon_entries.iter().any(|entry| entry.name == name) {
+        if let Some(pokemon) = pokedex.pokemon(&name).await? {
+            return Ok(PokemonLookup::Found(Box::new(pokemon)));
+        }
+    }
+
+    // Forms that share their name with a species are only suggested once.
+    let species_names: HashSet<_> = species_entries.iter().map(|entry| entry.name.as_str()).collect();
+    let candidates = species_entries.iter()
+        .map(|entry| entry.name.as_str())
+        .chain(pokemon_entries.iter().map(|entry| entry.name.as_str()).filter(|name| !species_names.contains(name)));
+
+    let suggestions = crate::utils::closest_matches(&name, candidates, 3)
+        .into_iter()
+        .map(display_name)
+        .collect();
+
+    Ok(PokemonLookup::NotFound(suggestions))
+}
+
+/// Suggests Pokémon species whose name starts with or contains the partial input,
+/// falling back to the most similar names if there are none.
+pub(super) async fn autocomplete_pokemon_name<R: RepositoryProvider + Send + Sync>(ctx: AppContext<'_, R, R::BackendError>, partial: &str) -> Vec<String> {
+    super::autocomplete_entry_name(ctx, Endpoint::PokemonSpecies, partial).await
 }
@@ -0,0 +1,117 @@
+use cipher_core::repository::RepositoryProvider;
+use poise::CreateReply;
+use rustemon::model::pokemon::Type;
+use rustemon::model::resource::NamedApiResource;
+use serenity::all::CreateEmbed;
+use serenity::all::CreateEmbedFooter;
+
+use crate::app::AppContext;
+use crate::app::AppError;
+use crate::pokeapi::display_name;
+use crate::pokeapi::english_name;
+use crate::pokeapi::Endpoint;
+
+use super::EntryLookup;
+
+/// Get information about types.
+#[poise::command(
+    slash_command,
+    guild_only,
+    rename = "type",
+    subcommands(
+        "list",
+        "search",
+    ),
+)]
+pub async fn type_<R: RepositoryProvider + Send + Sync>(
+    _ctx: AppContext<'_, R, R::BackendError>,
+) -> Result<(), AppError<R::BackendError>> {
+    Ok(())
+}
+
+/// List all of the types.
+#[poise::command(slash_command, guild_only)]
+async fn list<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[rename = "page"]
+    #[description = "The page to show. Default is 1."]
+    #[min = 1]
+    option_page_number: Option<usize>,
+    #[rename = "amount"]
+    #[description = "The number of results to show per page. Default is 10."]
+    #[min = 1]
+    #[max = 20]
+    option_amount: Option<usize>,
+) -> Result<(), AppError<R::BackendError>> {
+    super::list_entries(ctx, Endpoint::Type, "Types", option_page_number, option_amount).await
+}
+
+/// Search for a type by name and show how effective it is against other types.
+#[poise::command(slash_command, guild_only)]
+async fn search<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[description = "The name of the type"]
+    #[autocomplete = "super::matchup::autocomplete_type_name::<R>"]
+    name: String,
+) -> Result<(), AppError<R::BackendError>> {
+    ctx.defer_ephemeral().await?;
+
+    let pokedex = ctx.data.pokedex();
+
+    let option_type = match super::find_entry(pokedex, Endpoint::Type, &name).await {
+        Ok(EntryLookup::Found(entry)) => pokedex.pokemon_type(&entry.name).await?,
+        Ok(EntryLookup::NotFound(suggestions)) => return super::send_not_found(ctx, "type", &name, Ok(suggestions)).await,
+        Err(err) => return super::send_not_found(ctx, "type", &name, Err(err)).await,
+    };
+
+    let found = match option_type {
+        Some(found) => found,
+        None => return super::send_not_found(ctx, "type", &name, Ok(Vec::new())).await,
+    };
+
+    let colour = crate::utils::bot_color(&ctx).await;
+
+    let embed = type_embed(&found).color(colour);
+
+    ctx.send(CreateReply::default().embed(embed).ephemeral(true)).await?;
+
+    Ok(())
+}
+
+/// Shows the damage relations of a type as an effectiveness chart, from the attacking and the defending side.
+fn type_embed(found: &Type) -> CreateEmbed {
+    let title = format!("{} #{}", english_name(&found.names).unwrap_or_else(|| display_name(&found.name)), found.id);
+    let relations = &found.damage_relations;
+
+    let attacking = [
+        ("2×", &relations.double_damage_to),
+        ("½×", &relations.half_damage_to),
+        ("0×", &relations.no_damage_to),
+    ];
+
+    let defending = [
+        ("2×", &relations.double_damage_from),
+        ("½×", &relations.half_damage_from),
+        ("0×", &relations.no_damage_from),
+    ];
+
+    CreateEmbed::new()
+        .title(title)
+        .field("Attacking", effectiveness_lines(&attacking), true)
+        .field("Defending", effectiveness_lines(&defending), true)
+        .footer(CreateEmbedFooter::new(display_name(&found.generation.name)))
+}
+
+fn effectiveness_lines(multipliers: &[(&str, &Vec<NamedApiResource<Type>>)]) -> String {
+    multipliers.iter()
+        .map(|(multiplier, types)| {
+            let types = match types.is_empty() {
+                true => "—".to_string(),
+                false => types.iter().map(|other| display_name(&other.name)).collect::<Vec<_>>().join(", "),
+            };
+
+            format!("**{}** {}", multiplier, types)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
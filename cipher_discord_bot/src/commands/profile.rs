@@ -1,34 +1,79 @@
+use std::net::IpAddr;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 
+use chrono::DateTime;
+use chrono::Utc;
 use cipher_core::repository::profile_repository::NewProfile;
 use cipher_core::repository::profile_repository::Profile;
+use cipher_core::repository::profile_repository::ProfileFilter;
 use cipher_core::repository::profile_repository::ProfileRepository;
+use cipher_core::repository::profile_repository::ProfileTextField;
 use cipher_core::repository::user_repository::NewUser;
 use cipher_core::repository::user_repository::UserRepository;
+use cipher_core::repository::RepositoryError;
 use cipher_core::repository::RepositoryProvider;
+use poise::ChoiceParameter;
 use poise::CreateReply;
-use poise::ReplyHandle;
+use reqwest::redirect::Policy;
+use serde::Serialize;
+use serenity::all::Attachment;
 use serenity::all::ButtonStyle;
 use serenity::all::Color;
 use serenity::all::ComponentInteractionCollector;
 use serenity::all::CreateActionRow;
+use serenity::all::CreateAttachment;
 use serenity::all::CreateButton;
 use serenity::all::CreateEmbed;
 use serenity::all::CreateEmbedAuthor;
+use serenity::all::CreateEmbedFooter;
 use serenity::all::CreateInteractionResponse;
+use serenity::all::CreateMessage;
 use serenity::all::Member;
 use serenity::all::User;
+use serenity::http::StatusCode;
+use url::Host;
+use url::Url;
 use uuid::Uuid;
 
 use crate::app::AppContext;
 use crate::app::AppError;
+use crate::cli::ProfileImageOptions;
+use crate::paginator::Page;
+use crate::paginator::Paginator;
+use crate::paginator::PaginatorOutcome;
+use crate::pokeapi::Endpoint;
+use crate::pokeapi::PokedexEntry;
+use crate::pokeapi::PokedexError;
+use crate::pokeapi::PokedexSource;
+use crate::pokeapi::PokemonSummary;
+
+/// The number of profiles shown per page of search results.
+const SEARCH_RESULTS_PER_PAGE: usize = 10;
+/// The maximum number of choices Discord accepts in an autocomplete response.
+const AUTOCOMPLETE_CHOICE_LIMIT: u32 = 25;
+/// How long to wait for the host of a profile image to respond when probing its content type.
+const IMAGE_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+/// The largest profile image members can upload, in bytes.
+const MAX_IMAGE_UPLOAD_SIZE: u32 = 8 * 1024 * 1024;
+
+/// Editing a profile involves filling in several forms, so the editor waits longer than other menus.
+const EDITOR_TIMEOUT: Duration = Duration::from_secs(5 * 60);
 
 /// Edit and show profiles.
 #[poise::command(
     slash_command,
     subcommands(
+        "delete",
         "edit",
+        "erase",
+        "export",
+        "history",
+        "image",
         "overwrite",
+        "search",
         "show",
     ),
 )]
@@ -77,6 +122,174 @@ async fn show<R: RepositoryProvider + Send + Sync>(
     show_inner(ctx, member, ephemeral.unwrap_or(true)).await
 }
 
+/// Find members whose profile matches the given values.
+#[poise::command(
+    slash_command,
+    guild_only,
+)]
+async fn search<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[description = "The partner Pokémon to search for."]
+    #[autocomplete = "autocomplete_partner_pokemon::<R>"]
+    partner_pokemon: Option<String>,
+    #[description = "The starting region to search for."]
+    #[autocomplete = "autocomplete_starting_region::<R>"]
+    region: Option<String>,
+    #[description = "The nature to search for."]
+    #[autocomplete = "autocomplete_nature::<R>"]
+    nature: Option<String>,
+    #[description = "The trainer class to search for."]
+    #[autocomplete = "autocomplete_trainer_class::<R>"]
+    trainer_class: Option<String>,
+    #[description = "Hide reply from other users. Defaults to True."]
+    ephemeral: Option<bool>,
+) -> Result<(), AppError<R::BackendError>> {
+    let guild_id = ctx.guild_id().ok_or(AppError::UnknownCacheOrHttpError)?;
+    let ephemeral = ephemeral.unwrap_or(true);
+
+    let filter = ProfileFilter {
+        partner_pokemon,
+        starting_region: region,
+        trainer_class,
+        nature,
+        ..Default::default()
+    };
+
+    let criteria: Vec<_> = [
+        ("Partner Pokémon", &filter.partner_pokemon),
+        ("Region", &filter.starting_region),
+        ("Nature", &filter.nature),
+        ("Trainer Class", &filter.trainer_class),
+    ]
+        .into_iter()
+        .filter_map(|(name, value)| value.as_ref().map(|value| format!("{}: {}", name, value)))
+        .collect();
+
+    if criteria.is_empty() {
+        let embed = CreateEmbed::new()
+            .title("Profile Search")
+            .description("Please provide at least one value to search for.")
+            .color(crate::utils::bot_color(&ctx).await);
+
+        let reply = CreateReply::default()
+            .embed(embed)
+            .ephemeral(true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    // Listing the guild members may take longer than the interaction response deadline.
+    match ephemeral {
+        true => ctx.defer_ephemeral().await?,
+        false => ctx.defer().await?,
+    }
+
+    let colour = crate::utils::bot_color(&ctx).await;
+
+    // Profiles are not scoped to guilds, so only list members of the current guild.
+    let member_ids = crate::utils::guild_member_ids(&ctx, guild_id).await?;
+
+    let filter = ProfileFilter {
+        discord_user_ids: Some(member_ids.into_iter().collect()),
+        ..filter
+    };
+
+    // Only the number of matches is needed here, each page is read when it is shown.
+    let total = ctx.data.repository().await?.query_profiles(&filter, 0, 0).await?.total as usize;
+
+    if total == 0 {
+        let embed = CreateEmbed::new()
+            .title("Profile Search")
+            .description(format!("No members of this server have a profile matching:\n{}", criteria.join("\n")))
+            .color(colour);
+
+        let reply = CreateReply::default()
+            .embed(embed)
+            .ephemeral(ephemeral);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let max_page_number = total.div_ceil(SEARCH_RESULTS_PER_PAGE);
+
+    let mut paginator = Paginator::new(max_page_number).ephemeral(ephemeral);
+
+    paginator.run(ctx, |page_number| {
+        let filter = &filter;
+        let criteria = &criteria;
+
+        async move {
+            let mut repo = ctx.data.repository().await?;
+            let offset = ((page_number - 1) * SEARCH_RESULTS_PER_PAGE) as u64;
+            let page = repo.query_profiles(filter, SEARCH_RESULTS_PER_PAGE as u32, offset).await?;
+
+            let entries: Vec<_> = page.profiles
+                .iter()
+                .map(|(discord_user_id, profile)| {
+                    let values = [&profile.partner_pokemon, &profile.starting_region, &profile.nature, &profile.trainer_class]
+                        .into_iter()
+                        .flatten()
+                        .map(String::as_str)
+                        .collect::<Vec<_>>()
+                        .join(" · ");
+
+                    format!("<@{}> {}", discord_user_id, values)
+                })
+                .collect();
+
+            let embed = CreateEmbed::new()
+                .title("Profile Search")
+                .description(format!("{}\n\n{}", criteria.join("\n"), entries.join("\n")))
+                .footer(CreateEmbedFooter::new(format!("Page {}/{} · {} members", page_number, max_page_number, page.total)))
+                .color(colour);
+
+            Ok(embed)
+        }
+    }).await?;
+
+    Ok(())
+}
+
+async fn autocomplete_partner_pokemon<R: RepositoryProvider + Send + Sync>(ctx: AppContext<'_, R, R::BackendError>, partial: &str) -> Vec<String> {
+    autocomplete_profile_values(ctx, ProfileTextField::PartnerPokemon, partial).await
+}
+
+async fn autocomplete_starting_region<R: RepositoryProvider + Send + Sync>(ctx: AppContext<'_, R, R::BackendError>, partial: &str) -> Vec<String> {
+    autocomplete_profile_values(ctx, ProfileTextField::StartingRegion, partial).await
+}
+
+async fn autocomplete_nature<R: RepositoryProvider + Send + Sync>(ctx: AppContext<'_, R, R::BackendError>, partial: &str) -> Vec<String> {
+    autocomplete_profile_values(ctx, ProfileTextField::Nature, partial).await
+}
+
+async fn autocomplete_trainer_class<R: RepositoryProvider + Send + Sync>(ctx: AppContext<'_, R, R::BackendError>, partial: &str) -> Vec<String> {
+    autocomplete_profile_values(ctx, ProfileTextField::TrainerClass, partial).await
+}
+
+/// Suggests values of a profile field that are already used by active profiles.
+async fn autocomplete_profile_values<R>(ctx: AppContext<'_, R, R::BackendError>, field: ProfileTextField, partial: &str) -> Vec<String>
+where
+    R: RepositoryProvider + Send + Sync,
+{
+    let result = match ctx.data.repository().await {
+        Ok(mut repo) => repo.active_profile_values(field, partial, AUTOCOMPLETE_CHOICE_LIMIT).await,
+        Err(err) => Err(err),
+    };
+
+    match result {
+        // Discord rejects choices longer than 100 characters.
+        Ok(values) => values.into_iter().filter(|value| value.chars().count() <= 100).collect(),
+        Err(RepositoryError(err)) => {
+            log::warn!("Failed to autocomplete profile values: {}", err);
+            Vec::new()
+        },
+    }
+}
+
 /// Edit your profile.
 #[poise::command(
     slash_command,
@@ -106,6 +319,93 @@ async fn edit<R: RepositoryProvider + Send + Sync>(ctx: AppContext<'_, R, R::Bac
     Ok(())
 }
 
+/// Download all data stored about you as a JSON file.
+#[poise::command(
+    slash_command,
+    guild_only,
+)]
+async fn export<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[rename = "direct_message"]
+    #[description = "Send the file as a direct message instead of a hidden reply. Defaults to False."]
+    option_direct_message: Option<bool>,
+) -> Result<(), AppError<R::BackendError>> {
+    let mut repo = ctx.data.repository().await?;
+
+    let discord_user_id = ctx.author().id.get();
+    let option_user = repo.user_by_discord_user_id(discord_user_id).await?;
+    let profiles = repo.profiles_by_discord_id(discord_user_id).await?;
+
+    let personal_data = PersonalData {
+        exported_at: Utc::now(),
+        discord_user_id,
+        user_id: option_user.map(|user| user.id),
+        profiles: profiles.into_iter().map(PersonalDataProfile::from).collect(),
+    };
+
+    let data = serde_json::to_vec_pretty(&personal_data)?;
+    let filename = format!("profile-data-{}.json", discord_user_id);
+
+    if option_direct_message.unwrap_or(false) {
+        let message = CreateMessage::new()
+            .content("Here is all data stored about you.")
+            .add_file(CreateAttachment::bytes(data.clone(), &filename));
+
+        let (description, option_attachment) = match ctx.author().direct_message(ctx, message).await {
+            Ok(_) => ("Your data has been sent to you in a direct message.", None),
+            Err(err) => {
+                log::debug!("Failed to send personal data to user {} in a direct message: {}", discord_user_id, err);
+                ("Your direct messages are closed, so your data is attached here instead.", Some(CreateAttachment::bytes(data, filename)))
+            },
+        };
+
+        let embed = CreateEmbed::new()
+            .title("Personal Data Export")
+            .description(description)
+            .color(crate::utils::bot_color(&ctx).await);
+
+        let mut reply = CreateReply::default()
+            .embed(embed)
+            .ephemeral(true);
+
+        if let Some(attachment) = option_attachment {
+            reply = reply.attachment(attachment);
+        }
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let embed = CreateEmbed::new()
+        .title("Personal Data Export")
+        .description(format!(
+            "The attached file contains your user record and all {} saved versions of your profile.",
+            personal_data.profiles.len(),
+        ))
+        .color(crate::utils::bot_color(&ctx).await);
+
+    let reply = CreateReply::default()
+        .embed(embed)
+        .attachment(CreateAttachment::bytes(data, filename))
+        .ephemeral(true);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+/// Browse and restore previous versions of your profile.
+#[poise::command(
+    slash_command,
+    guild_only,
+)]
+async fn history<R: RepositoryProvider + Send + Sync>(ctx: AppContext<'_, R, R::BackendError>) -> Result<(), AppError<R::BackendError>> {
+    let member = ctx.author_member().await.ok_or(AppError::UnknownCacheOrHttpError)?.into_owned();
+
+    history_inner(ctx, member).await
+}
+
 /// Edit any user's profile. Staff only.
 #[poise::command(
     slash_command,
@@ -122,10 +422,262 @@ async fn overwrite<R: RepositoryProvider + Send + Sync>(
     Ok(())
 }
 
+/// Manage the images shown on your profile.
+#[poise::command(
+    slash_command,
+    guild_only,
+    subcommands(
+        "image_set",
+    ),
+)]
+async fn image<R: RepositoryProvider + Send + Sync>(
+    _ctx: AppContext<'_, R, R::BackendError>,
+) -> Result<(), AppError<R::BackendError>> {
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, ChoiceParameter)]
+enum ImageSlotChoice {
+    #[name = "Thumbnail"]
+    Thumbnail,
+    #[name = "Footer"]
+    Footer,
+}
+
+/// Upload an image to show on your profile.
+#[poise::command(
+    slash_command,
+    guild_only,
+    rename = "set",
+)]
+async fn image_set<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[description = "Where the image is shown on your profile."] slot: ImageSlotChoice,
+    #[description = "The image to upload."] image: Attachment,
+) -> Result<(), AppError<R::BackendError>> {
+    let options = ctx.data.profile_images();
+
+    let (storage_directory, storage_base_url) = match (&options.storage_directory, &options.storage_base_url) {
+        (Some(storage_directory), Some(storage_base_url)) => (storage_directory, storage_base_url),
+        _ => {
+            let embed = CreateEmbed::new()
+                .title("Validation Error")
+                .description("Image uploads are not configured on this bot. Use `/profile edit` to link an image instead.")
+                .color(Color::RED);
+
+            let reply = CreateReply::default()
+                .embed(embed)
+                .ephemeral(true);
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        },
+    };
+
+    let mut errors = Vec::new();
+    if !image.content_type.as_deref().is_some_and(|content_type| content_type.starts_with("image/")) {
+        errors.push(format!("`{}` is not an image.", image.filename));
+    }
+    if image.size > MAX_IMAGE_UPLOAD_SIZE {
+        errors.push(format!("`{}` is larger than the {} MiB upload limit.", image.filename, MAX_IMAGE_UPLOAD_SIZE / (1024 * 1024)));
+    }
+
+    if !errors.is_empty() {
+        let embed = CreateEmbed::new()
+            .title("Validation Error")
+            .description(errors.join("\n"))
+            .color(Color::RED);
+
+        let reply = CreateReply::default()
+            .embed(embed)
+            .ephemeral(true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    // Downloading and re-hosting the image may take longer than the interaction response deadline.
+    ctx.defer_ephemeral().await?;
+
+    let discord_user_id = ctx.author().id.get();
+    let bytes = image.download().await?;
+
+    // The stored file is served as is, so its type is taken from its contents rather than the uploaded filename.
+    let extension = match image_extension(&bytes) {
+        Some(extension) => extension,
+        None => {
+            let embed = CreateEmbed::new()
+                .title("Validation Error")
+                .description(format!("`{}` is not a PNG, JPEG, GIF or WebP image.", image.filename))
+                .color(Color::RED);
+
+            let reply = CreateReply::default()
+                .embed(embed)
+                .ephemeral(true);
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        },
+    };
+
+    let url = store_profile_image(storage_directory, storage_base_url, extension, bytes).await?;
+
+    let mut repo = ctx.data.repository().await?;
+
+    let user = match repo.user_by_discord_user_id(discord_user_id).await? {
+        Some(user) => user,
+        None => repo.insert_user(NewUser { discord_user_id }).await?,
+    };
+
+    let mut new_profile = repo.active_profile_by_discord_id(discord_user_id).await?
+        .map(Profile::into_new)
+        .unwrap_or_default();
+
+    new_profile.user_id = user.id;
+
+    let option_replaced_url = match slot {
+        ImageSlotChoice::Thumbnail => new_profile.thumbnail_url.replace(url),
+        ImageSlotChoice::Footer => new_profile.image_url.replace(url),
+    };
+
+    repo.insert_profile(new_profile).await?;
+
+    remove_stored_images(options, option_replaced_url.into_iter().collect()).await;
+
+    let embed = CreateEmbed::new()
+        .title("Saved")
+        .description(format!("Your {} image has been uploaded and saved.", slot.name().to_lowercase()))
+        .color(crate::utils::bot_color(&ctx).await);
+
+    let reply = CreateReply::default()
+        .embed(embed)
+        .ephemeral(true);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+/// Re-hosts an uploaded profile image in the storage directory and returns its new url.
+async fn store_profile_image(storage_directory: &Path, storage_base_url: &Url, extension: &str, bytes: Vec<u8>) -> std::io::Result<String> {
+    let stored_filename = format!("{}.{}", Uuid::new_v4(), extension);
+
+    tokio::fs::create_dir_all(storage_directory).await?;
+    tokio::fs::write(storage_directory.join(&stored_filename), bytes).await?;
+
+    Ok(format!("{}/{}", storage_base_url.as_str().trim_end_matches('/'), stored_filename))
+}
+
+/// Returns the path of an image uploaded with `/profile image set`, or `None` if the url points anywhere else.
+fn stored_image_path(options: &ProfileImageOptions, url: &str) -> Option<PathBuf> {
+    let storage_directory = options.storage_directory.as_ref()?;
+    let storage_base_url = options.storage_base_url.as_ref()?;
+
+    let stored_filename = url.strip_prefix(storage_base_url.as_str().trim_end_matches('/'))?.strip_prefix('/')?;
+
+    // Only names the bot could have generated are accepted, so a url can never point outside the directory.
+    let (stem, extension) = stored_filename.split_once('.')?;
+    if Uuid::try_parse(stem).is_err() || !["png", "jpg", "gif", "webp"].contains(&extension) {
+        return None;
+    }
+
+    Some(storage_directory.join(stored_filename))
+}
+
+/// Deletes the uploaded images among `urls` from the storage directory, so they are no longer served.
+async fn remove_stored_images(options: &ProfileImageOptions, urls: Vec<String>) {
+    let paths: Vec<_> = urls.iter().filter_map(|url| stored_image_path(options, url)).collect();
+
+    for path in paths {
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => {},
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {},
+            Err(err) => log::error!("Failed to delete profile image {}: {}", path.display(), err),
+        }
+    }
+}
+
+/// Returns the file extension of an image from its leading bytes, or `None` if it is not a PNG, JPEG, GIF or WebP image.
+fn image_extension(bytes: &[u8]) -> Option<&'static str> {
+    match bytes {
+        [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n', ..] => Some("png"),
+        [0xff, 0xd8, 0xff, ..] => Some("jpg"),
+        [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some("gif"),
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some("webp"),
+        _ => None,
+    }
+}
+
+/// Permanently delete your profile and all of its saved versions.
+#[poise::command(
+    slash_command,
+    guild_only,
+)]
+async fn delete<R: RepositoryProvider + Send + Sync>(ctx: AppContext<'_, R, R::BackendError>) -> Result<(), AppError<R::BackendError>> {
+    let user = ctx.author().clone();
+
+    delete_inner(ctx, user).await
+}
+
+/// Permanently delete a member's profile and all of its saved versions. Staff only.
+#[poise::command(
+    slash_command,
+    guild_only,
+    hide_in_help,
+    check = "crate::checks::is_staff",
+)]
+async fn erase<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[description = "The member whose data you wish to delete. Only bot owners can select users who left the server."] user: User,
+) -> Result<(), AppError<R::BackendError>> {
+    // Staff roles are scoped to a guild, so staff may only erase the data of their own guild's members.
+    let is_owner = ctx.framework().options.owners.contains(&ctx.author().id);
+
+    if !is_owner && !is_guild_member(ctx, &user).await? {
+        let embed = CreateEmbed::new()
+            .title("Validation Error")
+            .description(format!("<@{}> is not a member of this server. Only bot owners can erase the data of users outside of it.", user.id))
+            .color(Color::RED);
+
+        let reply = CreateReply::default()
+            .embed(embed)
+            .ephemeral(true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    delete_inner(ctx, user).await
+}
+
+/// Whether the user is a member of the guild the command was invoked in.
+async fn is_guild_member<R>(ctx: AppContext<'_, R, R::BackendError>, user: &User) -> Result<bool, AppError<R::BackendError>>
+where
+    R: RepositoryProvider + Send + Sync,
+{
+    let guild_id = ctx.guild_id().ok_or(AppError::UnknownCacheOrHttpError)?;
+
+    match guild_id.member(ctx, user.id).await {
+        Ok(_) => Ok(true),
+        Err(serenity::Error::Http(err)) if err.status_code() == Some(StatusCode::NOT_FOUND) => Ok(false),
+        Err(err) => Err(AppError::from(err)),
+    }
+}
+
 async fn show_inner<R>(ctx: AppContext<'_, R, R::BackendError>, member: Member, ephemeral: bool) -> Result<(), AppError<R::BackendError>>
 where
     R: RepositoryProvider + Send + Sync,
 {
+    // Looking up the partner Pokémon may take longer than the interaction response deadline.
+    match ephemeral {
+        true => ctx.defer_ephemeral().await?,
+        false => ctx.defer().await?,
+    }
+
     let mut repo = ctx.data.repository().await?;
 
     let option_profile = repo.active_profile_by_discord_id(member.user.id.get()).await?;
@@ -140,251 +692,517 @@ where
     Ok(())
 }
 
+async fn history_inner<R>(ctx: AppContext<'_, R, R::BackendError>, member: Member) -> Result<(), AppError<R::BackendError>>
+where
+    R: RepositoryProvider + Send + Sync,
+{
+    // Looking up the partner Pokémon may take longer than the interaction response deadline.
+    ctx.defer_ephemeral().await?;
+
+    let mut repo = ctx.data.repository().await?;
+
+    let mut profiles = repo.profiles_by_discord_id(member.user.id.get()).await?;
+
+    if profiles.is_empty() {
+        let embed = CreateEmbed::new()
+            .title("No Profile History")
+            .description("There are no saved versions of this profile. Use `/profile edit` to create one.")
+            .color(crate::utils::bot_color(&ctx).await);
+
+        let reply = CreateReply::default()
+            .embed(embed)
+            .ephemeral(true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let mut paginator = Paginator::new(profiles.len());
+
+    loop {
+        let restore_button_id = Uuid::new_v4().to_string();
+
+        let outcome = paginator.run(ctx, |page_number| {
+            let profiles = &profiles;
+            let member = &member;
+            let restore_button_id = &restore_button_id;
+
+            async move {
+                let profile = &profiles[page_number - 1];
+                let option_active_profile = profiles.iter().find(|profile| profile.is_active);
+
+                let status = match profile.is_active {
+                    true => "Active",
+                    false => "Inactive",
+                };
+
+                let changes = match option_active_profile {
+                    Some(active_profile) if active_profile.id != profile.id => {
+                        let changes = profile_diff(active_profile, profile);
+                        match changes.is_empty() {
+                            true => "Identical to the active version.".to_string(),
+                            false => changes.join("\n"),
+                        }
+                    },
+                    Some(_) => "This is the active version.".to_string(),
+                    None => "There is no active version to compare against.".to_string(),
+                };
+
+                let embed = ProfileEmbed::from_profile(&ctx, member, Some(profile)).await
+                    .into_embed()
+                    .title(format!("Profile Version {}/{}", page_number, profiles.len()))
+                    .field("\u{200E}", "**Version Details**", false) // Invisible character to use title as a spacer
+                    .field("Created", format!("<t:{}:f>", profile.created_at.timestamp()), true)
+                    .field("Status", status, true)
+                    .field("Changes From Active Version", truncate_field_value(changes), false);
+
+                let restore_button = CreateButton::new(restore_button_id)
+                    .label("Restore This Version")
+                    .style(ButtonStyle::Primary)
+                    .disabled(profile.is_active);
+
+                Ok(Page::new(embed).action(restore_button_id, restore_button))
+            }
+        }).await?;
+
+        let mci = match outcome {
+            PaginatorOutcome::Action { interaction, .. } => *interaction,
+            PaginatorOutcome::TimedOut => break,
+        };
+
+        // Restoring and rendering the diff may take longer than the interaction response deadline.
+        mci.create_response(ctx, CreateInteractionResponse::Acknowledge).await?;
+
+        let profile = &profiles[paginator.current_page_number() - 1];
+        repo.set_active_profile(profile.user_id, profile.id).await?;
+
+        let restored_profile_id = profile.id;
+        profiles = repo.profiles_by_discord_id(member.user.id.get()).await?;
+
+        let restored_page_number = profiles.iter().position(|profile| profile.id == restored_profile_id).unwrap_or(0) + 1;
+        paginator.set_page_count(profiles.len());
+        paginator.set_page_number(restored_page_number);
+    }
+
+    Ok(())
+}
+
+async fn delete_inner<R>(ctx: AppContext<'_, R, R::BackendError>, user: User) -> Result<(), AppError<R::BackendError>>
+where
+    R: RepositoryProvider + Send + Sync,
+{
+    let mut repo = ctx.data.repository().await?;
+
+    let is_self = user.id == ctx.author().id;
+    let subject = match is_self {
+        true => "your".to_string(),
+        false => format!("<@{}>'s", user.id),
+    };
+
+    let stored_user = match repo.user_by_discord_user_id(user.id.get()).await? {
+        Some(stored_user) => stored_user,
+        None => {
+            let embed = CreateEmbed::new()
+                .title("No Profile Data")
+                .description(format!("There is no stored data for {} profile.", subject))
+                .color(crate::utils::bot_color(&ctx).await);
+
+            let reply = CreateReply::default()
+                .embed(embed)
+                .ephemeral(true);
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        },
+    };
+
+    let version_count = repo.profiles_by_user_id(stored_user.id).await?.len();
+
+    let embed = CreateEmbed::new()
+        .title("Delete Profile Data")
+        .description(format!(
+            "This will permanently delete {} profile and all {} of its saved versions. This can not be undone.",
+            subject,
+            version_count,
+        ))
+        .color(Color::RED);
+
+    let confirm_button_id = Uuid::new_v4().to_string();
+    let cancel_button_id = Uuid::new_v4().to_string();
+    let buttons = CreateActionRow::Buttons(vec![
+        CreateButton::new(&confirm_button_id).label("Delete Permanently").style(ButtonStyle::Danger),
+        CreateButton::new(&cancel_button_id).label("Cancel").style(ButtonStyle::Secondary),
+    ]);
+
+    let reply = CreateReply::default()
+        .embed(embed)
+        .components(vec![buttons])
+        .ephemeral(true);
+
+    let reply_handle = ctx.send(reply).await?;
+
+    let collector = ComponentInteractionCollector::new(ctx)
+        .author_id(ctx.author().id)
+        .channel_id(ctx.channel_id())
+        .custom_ids(vec![confirm_button_id.clone(), cancel_button_id])
+        .timeout(Duration::from_secs(60));
+
+    let embed = match collector.await {
+        Some(mci) if mci.data.custom_id == confirm_button_id => {
+            mci.create_response(ctx, CreateInteractionResponse::Acknowledge).await?;
+
+            let profiles = repo.profiles_by_user_id(stored_user.id).await?;
+
+            repo.delete_user(stored_user.id).await?;
+
+            let image_urls = profiles.into_iter()
+                .flat_map(|profile| [profile.thumbnail_url, profile.image_url])
+                .flatten()
+                .collect();
+
+            remove_stored_images(ctx.data.profile_images(), image_urls).await;
+
+            if !is_self {
+                log::info!(
+                    target: "cipher_discord_bot::audit",
+                    "User {} erased the profile data of user {} ({} saved versions) in guild {}.",
+                    ctx.author().id,
+                    user.id,
+                    version_count,
+                    ctx.guild_id().map(|guild_id| guild_id.get()).unwrap_or_default(),
+                );
+            }
+
+            CreateEmbed::new()
+                .title("Profile Data Deleted")
+                .description(format!("All of {} profile data has been permanently deleted.", subject))
+                .color(crate::utils::bot_color(&ctx).await)
+        },
+        Some(mci) => {
+            mci.create_response(ctx, CreateInteractionResponse::Acknowledge).await?;
+
+            CreateEmbed::new()
+                .title("Deletion Cancelled")
+                .description("Nothing has been deleted.")
+                .color(crate::utils::bot_color(&ctx).await)
+        },
+        None => CreateEmbed::new()
+            .title("Deletion Timed Out")
+            .description(format!("Nothing has been deleted. Please use `/profile {}` again to continue.", ctx.command().name))
+            .color(crate::utils::bot_color(&ctx).await),
+    };
+
+    let reply = CreateReply::default()
+        .embed(embed)
+        .components(vec![])
+        .ephemeral(true);
+
+    reply_handle.edit(ctx.into(), reply).await?;
+
+    Ok(())
+}
+
 async fn edit_inner<R>(ctx: AppContext<'_, R, R::BackendError>, member: Member) -> Result<(), AppError<R::BackendError>>
 where
     R: RepositoryProvider + Send + Sync,
 {
+    // Looking up the partner Pokémon may take longer than the interaction response deadline.
+    ctx.defer_ephemeral().await?;
+
     let mut repo = ctx.data.repository().await?;
 
-    let mut option_reply_handle: Option<ReplyHandle> = None;
     let mut option_profile = repo.active_profile_by_discord_id(member.user.id.get()).await?.map(Profile::into_new);
+    let mut paginator = Paginator::new(1).timeout(EDITOR_TIMEOUT);
 
-    'update_reply: loop {
-        let embed = ProfileEmbed::from_new_profile(&ctx, &member, option_profile.as_ref()).await.into_embed();
-
+    loop {
         let pokemon_info_button_id = Uuid::new_v4().to_string();
         let personal_info_button_id = Uuid::new_v4().to_string();
         let friend_codes_button_id = Uuid::new_v4().to_string();
         let images_button_id = Uuid::new_v4().to_string();
         let save_button_id = Uuid::new_v4().to_string();
-        let buttons = CreateActionRow::Buttons(vec![
-            CreateButton::new(&pokemon_info_button_id).label("Edit Pokémon Info").style(ButtonStyle::Secondary),
-            CreateButton::new(&personal_info_button_id).label("Edit Personal Info").style(ButtonStyle::Secondary),
-            CreateButton::new(&friend_codes_button_id).label("Edit Friend Codes").style(ButtonStyle::Secondary),
-            CreateButton::new(&images_button_id).label("Edit Images").style(ButtonStyle::Secondary),
-            CreateButton::new(&save_button_id).label("Save").style(ButtonStyle::Primary),
-        ]);
 
-        let reply = CreateReply::default()
-            .embed(embed)
-            .components(vec![buttons])
-            .ephemeral(true);
+        let outcome = paginator.run(ctx, |_| {
+            let option_profile = &option_profile;
+            let member = &member;
+            let buttons = [
+                (&pokemon_info_button_id, "Edit Pokémon Info", ButtonStyle::Secondary),
+                (&personal_info_button_id, "Edit Personal Info", ButtonStyle::Secondary),
+                (&friend_codes_button_id, "Edit Friend Codes", ButtonStyle::Secondary),
+                (&images_button_id, "Edit Images", ButtonStyle::Secondary),
+                (&save_button_id, "Save", ButtonStyle::Primary),
+            ];
+
+            async move {
+                let embed = ProfileEmbed::from_new_profile(&ctx, member, option_profile.as_ref()).await.into_embed();
+
+                let page = buttons.into_iter().fold(Page::new(embed), |page, (button_id, label, style)| {
+                    page.action(button_id, CreateButton::new(button_id).label(label).style(style))
+                });
+
+                Ok(page)
+            }
+        }).await?;
+
+        let (custom_id, mci) = match outcome {
+            PaginatorOutcome::Action { custom_id, interaction } => (custom_id, *interaction),
+            PaginatorOutcome::TimedOut => {
+                let embed = CreateEmbed::new()
+                    .title("Editor Timed Out")
+                    .description("Your changes have not been saved. Please use `/profile edit` again to continue.")
+                    .color(crate::utils::bot_color(&ctx).await);
+
+                let reply = CreateReply::default()
+                    .embed(embed)
+                    .components(vec![])
+                    .ephemeral(true);
+
+                paginator.edit(ctx, reply).await?;
+
+                break;
+            },
+        };
+
+        if custom_id == pokemon_info_button_id {
+            let option_defaults = option_profile.clone().map(|profile| EditPokemonInfoModal {
+                trainer_class: profile.trainer_class,
+                nature: profile.nature,
+                partner_pokemon: profile.partner_pokemon,
+                starting_region: profile.starting_region,
+            });
+
+            let mut data = match poise::execute_modal_on_component_interaction(ctx, mci.clone(), option_defaults, None).await? {
+                Some(data) => data,
+                None => continue,
+            };
+
+            if let Err(errors) = data.validate(ctx.data.pokedex()).await {
+                let mut embed_description = String::new();
+
+                for error in errors {
+                    embed_description.push_str(&error);
+                    embed_description.push('\n');
+                }
+                embed_description.pop();
+
+                let embed = CreateEmbed::new()
+                    .title("Validation Error")
+                    .description(embed_description)
+                    .color(Color::RED);
+
+                let reply = CreateReply::default()
+                    .embed(embed)
+                    .ephemeral(true);
 
-        let reply_handle = match option_reply_handle {
-            Some(reply_handle) => {
-                reply_handle.edit(ctx.into(), reply).await?;
-                reply_handle
-            },
-            None => ctx.send(reply).await?,
-        };
+                ctx.send(reply).await?;
+
+                continue;
+            }
 
-        'interaction_response: loop {
-            let collector = ComponentInteractionCollector::new(ctx)
-                .author_id(ctx.author().id)
-                .channel_id(ctx.channel_id())
-                .timeout(Duration::from_secs(60));
+            let profile = match option_profile {
+                Some(mut profile) => {
+                    profile.trainer_class = data.trainer_class;
+                    profile.nature = data.nature;
+                    profile.partner_pokemon = data.partner_pokemon;
+                    profile.starting_region = data.starting_region;
+                    profile
+                },
+                None => NewProfile {
+                    trainer_class: data.trainer_class,
+                    nature: data.nature,
+                    partner_pokemon: data.partner_pokemon,
+                    starting_region: data.starting_region,
+                    ..Default::default()
+                },
+            };
 
-            let mci = match collector.await {
-                Some(mci) => mci,
-                None => {
-                    let embed = CreateEmbed::new()
-                        .title("Editor Timed Out")
-                        .description("Your changes have not been saved. Please use `/profile edit` again to continue.")
-                        .color(crate::utils::bot_color(&ctx).await);
+            option_profile = Some(profile);
+            continue;
+        }
 
-                    let reply = CreateReply::default()
-                        .embed(embed)
-                        .components(vec![])
-                        .ephemeral(true);
+        if custom_id == personal_info_button_id {
+            let option_defaults = option_profile.clone().map(|profile| EditPersonalInfoModal {
+                favourite_food: profile.favourite_food,
+                likes: profile.likes,
+                quotes: profile.quotes,
+            });
 
-                    reply_handle.edit(ctx.into(), reply).await?;
+            let data = match poise::execute_modal_on_component_interaction(ctx, mci.clone(), option_defaults, None).await? {
+                Some(data) => data,
+                None => continue,
+            };
 
-                    break 'update_reply;
+            let profile = match option_profile {
+                Some(mut profile) => {
+                    profile.favourite_food = data.favourite_food;
+                    profile.likes = data.likes;
+                    profile.quotes = data.quotes;
+                    profile
+                },
+                None => NewProfile {
+                    favourite_food: data.favourite_food,
+                    likes: data.likes,
+                    quotes: data.quotes,
+                    ..Default::default()
                 },
             };
 
-            if mci.data.custom_id == pokemon_info_button_id {
-                let option_defaults = option_profile.clone().map(|profile| EditPokemonInfoModal {
-                    trainer_class: profile.trainer_class,
-                    nature: profile.nature,
-                    partner_pokemon: profile.partner_pokemon,
-                    starting_region: profile.starting_region,
-                });
+            option_profile = Some(profile);
+            continue;
+        }
 
-                let data = match poise::execute_modal_on_component_interaction(ctx, mci.clone(), option_defaults, None).await? {
-                    Some(data) => data,
-                    None => continue 'interaction_response,
-                };
+        if custom_id == friend_codes_button_id {
+            let option_defaults = option_profile.clone().map(|profile| EditCodesModal {
+                pokemon_go_code: profile.pokemon_go_code,
+                pokemon_pocket_code: profile.pokemon_pocket_code,
+                switch_code: profile.switch_code,
+            });
 
-                let profile = match option_profile {
-                    Some(mut profile) => {
-                        profile.trainer_class = data.trainer_class;
-                        profile.nature = data.nature;
-                        profile.partner_pokemon = data.partner_pokemon;
-                        profile.starting_region = data.starting_region;
-                        profile
-                    },
-                    None => NewProfile {
-                        trainer_class: data.trainer_class,
-                        nature: data.nature,
-                        partner_pokemon: data.partner_pokemon,
-                        starting_region: data.starting_region,
-                        ..Default::default()
-                    },
-                };
+            let mut data = match poise::execute_modal_on_component_interaction(ctx, mci.clone(), option_defaults, None).await? {
+                Some(data) => data,
+                None => continue,
+            };
 
-                option_profile = Some(profile);
-                break 'interaction_response;
-            }
+            if let Err(errors) = data.validate() {
+                let mut embed_description = String::new();
 
-            if mci.data.custom_id == personal_info_button_id {
-                let option_defaults = option_profile.clone().map(|profile| EditPersonalInfoModal {
-                    favourite_food: profile.favourite_food,
-                    likes: profile.likes,
-                    quotes: profile.quotes,
-                });
+                for error in errors {
+                    embed_description.push_str(&error);
+                    embed_description.push('\n');
+                }
+                embed_description.pop();
 
-                let data = match poise::execute_modal_on_component_interaction(ctx, mci.clone(), option_defaults, None).await? {
-                    Some(data) => data,
-                    None => continue 'interaction_response,
-                };
+                let embed = CreateEmbed::new()
+                    .title("Validation Error")
+                    .description(embed_description)
+                    .color(Color::RED);
 
-                let profile = match option_profile {
-                    Some(mut profile) => {
-                        profile.favourite_food = data.favourite_food;
-                        profile.likes = data.likes;
-                        profile.quotes = data.quotes;
-                        profile
-                    },
-                    None => NewProfile {
-                        favourite_food: data.favourite_food,
-                        likes: data.likes,
-                        quotes: data.quotes,
-                        ..Default::default()
-                    },
-                };
+                let reply = CreateReply::default()
+                    .embed(embed)
+                    .ephemeral(true);
+
+                ctx.send(reply).await?;
 
-                option_profile = Some(profile);
-                break 'interaction_response;
+                continue;
             }
 
-            if mci.data.custom_id == friend_codes_button_id {
-                let option_defaults = option_profile.clone().map(|profile| EditCodesModal {
-                    pokemon_go_code: profile.pokemon_go_code,
-                    pokemon_pocket_code: profile.pokemon_pocket_code,
-                    switch_code: profile.switch_code,
-                });
+            let profile = match option_profile {
+                Some(mut profile) => {
+                    profile.pokemon_go_code = data.pokemon_go_code;
+                    profile.pokemon_pocket_code = data.pokemon_pocket_code;
+                    profile.switch_code = data.switch_code;
+                    profile
+                },
+                None => NewProfile {
+                    pokemon_go_code: data.pokemon_go_code,
+                    pokemon_pocket_code: data.pokemon_pocket_code,
+                    switch_code: data.switch_code,
+                    ..Default::default()
+                },
+            };
 
-                let mut data = match poise::execute_modal_on_component_interaction(ctx, mci.clone(), option_defaults, None).await? {
-                    Some(data) => data,
-                    None => continue 'interaction_response,
-                };
+            option_profile = Some(profile);
+            continue;
+        }
 
-                if let Err(errors) = data.validate() {
-                    let mut embed_description = String::new();
+        if custom_id == images_button_id {
+            let option_defaults = option_profile.clone().map(|profile| EditImagesModal {
+                thumbnail_url: profile.thumbnail_url,
+                image_url: profile.image_url,
+            });
 
-                    for error in errors {
-                        embed_description.push_str(&error);
-                        embed_description.push('\n');
-                    }
-                    embed_description.pop();
+            let mut data = match poise::execute_modal_on_component_interaction(ctx, mci.clone(), option_defaults, None).await? {
+                Some(data) => data,
+                None => continue,
+            };
 
-                    let embed = CreateEmbed::new()
-                        .title("Validation Error")
-                        .description(embed_description)
-                        .color(Color::RED);
+            if let Err(errors) = data.validate(ctx.data.profile_images()).await {
+                let mut embed_description = String::new();
 
-                    let reply = CreateReply::default()
-                        .embed(embed)
-                        .ephemeral(true);
+                for error in errors {
+                    embed_description.push_str(&error);
+                    embed_description.push('\n');
+                }
+                embed_description.pop();
 
-                    ctx.send(reply).await?;
+                let embed = CreateEmbed::new()
+                    .title("Validation Error")
+                    .description(embed_description)
+                    .color(Color::RED);
 
-                    continue 'interaction_response;
-                }
+                let reply = CreateReply::default()
+                    .embed(embed)
+                    .ephemeral(true);
 
-                let profile = match option_profile {
-                    Some(mut profile) => {
-                        profile.pokemon_go_code = data.pokemon_go_code;
-                        profile.pokemon_pocket_code = data.pokemon_pocket_code;
-                        profile.switch_code = data.switch_code;
-                        profile
-                    },
-                    None => NewProfile {
-                        pokemon_go_code: data.pokemon_go_code,
-                        pokemon_pocket_code: data.pokemon_pocket_code,
-                        switch_code: data.switch_code,
-                        ..Default::default()
-                    },
-                };
+                ctx.send(reply).await?;
 
-                option_profile = Some(profile);
-                break 'interaction_response;
+                continue;
             }
 
-            if mci.data.custom_id == images_button_id {
-                let option_defaults = option_profile.clone().map(|profile| EditImagesModal {
-                    thumbnail_url: profile.thumbnail_url,
-                    image_url: profile.image_url,
-                });
+            let profile = match option_profile {
+                Some(mut profile) => {
+                    profile.thumbnail_url = data.thumbnail_url;
+                    profile.image_url = data.image_url;
+                    profile
+                },
+                None => NewProfile {
+                    thumbnail_url: data.thumbnail_url,
+                    image_url: data.image_url,
+                    ..Default::default()
+                },
+            };
 
-                let data = match poise::execute_modal_on_component_interaction(ctx, mci.clone(), option_defaults, None).await? {
-                    Some(data) => data,
-                    None => continue 'interaction_response,
-                };
+            option_profile = Some(profile);
+            continue;
+        }
 
-                let profile = match option_profile {
-                    Some(mut profile) => {
-                        profile.thumbnail_url = data.thumbnail_url;
-                        profile.image_url = data.image_url;
-                        profile
-                    },
-                    None => NewProfile {
-                        thumbnail_url: data.thumbnail_url,
-                        image_url: data.image_url,
-                        ..Default::default()
-                    },
-                };
+        if custom_id == save_button_id {
+            mci.create_response(ctx, CreateInteractionResponse::Acknowledge).await?;
 
-                option_profile = Some(profile);
-                break 'interaction_response;
-            }
+            let mut new_profile = match option_profile.clone() {
+                Some(new_profile) => new_profile,
+                None => continue,
+            };
 
-            if mci.data.custom_id == save_button_id {
-                let mut new_profile = match option_profile {
-                    Some(new_profile) => new_profile,
-                    None => break,
-                };
+            let discord_user_id = member.user.id.get();
+            let user = match repo.user_by_discord_user_id(discord_user_id).await? {
+                Some(user) => user,
+                None => repo.insert_user(NewUser { discord_user_id }).await?,
+            };
 
-                let discord_user_id = member.user.id.get();
-                let user = match repo.user_by_discord_user_id(discord_user_id).await? {
-                    Some(user) => user,
-                    None => repo.insert_user(NewUser { discord_user_id }).await?,
-                };
+            new_profile.user_id = user.id;
 
-                new_profile.user_id = user.id;
+            let option_previous_profile = repo.active_profile_by_discord_id(discord_user_id).await?;
 
-                repo.insert_profile(new_profile).await?;
+            repo.insert_profile(new_profile.clone()).await?;
 
-                let embed = CreateEmbed::new()
-                    .title("Saved")
-                    .description("Your changes have been saved successfully!")
-                    .color(crate::utils::bot_color(&ctx).await);
+            // Uploaded images that were replaced or removed in the editor are no longer shown anywhere.
+            if let Some(previous_profile) = option_previous_profile {
+                let kept_urls = [new_profile.thumbnail_url, new_profile.image_url];
+                let replaced_urls = [previous_profile.thumbnail_url, previous_profile.image_url]
+                    .into_iter()
+                    .filter(|option_url| option_url.is_some() && !kept_urls.contains(option_url))
+                    .flatten()
+                    .collect();
 
-                let reply = CreateReply::default()
-                    .embed(embed)
-                    .components(vec![])
-                    .ephemeral(true);
+                remove_stored_images(ctx.data.profile_images(), replaced_urls).await;
+            }
 
-                reply_handle.edit(ctx.into(), reply).await?;
+            let embed = CreateEmbed::new()
+                .title("Saved")
+                .description("Your changes have been saved successfully!")
+                .color(crate::utils::bot_color(&ctx).await);
 
-                break 'update_reply;
-            }
+            let reply = CreateReply::default()
+                .embed(embed)
+                .components(vec![])
+                .ephemeral(true);
 
-            mci.create_response(ctx, CreateInteractionResponse::Acknowledge).await?;
-        }
+            paginator.edit(ctx, reply).await?;
 
-        option_reply_handle = Some(reply_handle);
+            break;
+        }
     }
 
     Ok(())
@@ -402,6 +1220,7 @@ struct ProfileEmbed {
     trainer_class: Option<String>,
     nature: Option<String>,
     partner_pokemon: Option<String>,
+    partner_pokemon_summary: Option<PokemonSummary>,
     favourite_food: Option<String>,
     starting_region: Option<String>,
     likes: Option<String>,
@@ -439,6 +1258,7 @@ impl ProfileEmbed {
 
                 trainer_class: profile.trainer_class,
                 nature: profile.nature,
+                partner_pokemon_summary: partner_pokemon_summary(ctx, profile.partner_pokemon.as_deref()).await,
                 partner_pokemon: profile.partner_pokemon,
                 favourite_food: profile.favourite_food,
                 starting_region: profile.starting_region,
@@ -484,6 +1304,7 @@ impl ProfileEmbed {
 
                 trainer_class: profile.trainer_class,
                 nature: profile.nature,
+                partner_pokemon_summary: partner_pokemon_summary(ctx, profile.partner_pokemon.as_deref()).await,
                 partner_pokemon: profile.partner_pokemon,
                 favourite_food: profile.favourite_food,
                 starting_region: profile.starting_region,
@@ -511,7 +1332,12 @@ impl ProfileEmbed {
             .author(embed_author)
             .color(self.color);
 
-        if let Some(thumbnail_url) = self.thumbnail_url {
+        // Members without a thumbnail of their own get their partner Pokémon's sprite.
+        let thumbnail_url = self.thumbnail_url.or_else(|| {
+            self.partner_pokemon_summary.as_ref().and_then(|summary| summary.sprite_url.clone())
+        });
+
+        if let Some(thumbnail_url) = thumbnail_url {
             embed = embed.thumbnail(thumbnail_url);
         }
         if let Some(image_url) = self.image_url {
@@ -528,7 +1354,11 @@ impl ProfileEmbed {
             is_profile_empty = false;
         }
         if let Some(partner_pokemon) = self.partner_pokemon {
-            embed = embed.field("Pokémon", partner_pokemon, true);
+            let value = match self.partner_pokemon_summary {
+                Some(summary) => format!("{} #{}\n{}", summary.name, summary.dex_number, summary.types.join(" / ")),
+                None => partner_pokemon,
+            };
+            embed = embed.field("Pokémon", value, true);
             is_profile_empty = false;
         }
         if let Some(favourite_food) = self.favourite_food {
@@ -578,6 +1408,124 @@ impl ProfileEmbed {
     }
 }
 
+/// Looks up the partner Pokémon of a profile, returning `None` if it is not a recognised Pokémon.
+async fn partner_pokemon_summary<R>(
+    ctx: &AppContext<'_, R, R::BackendError>,
+    option_partner_pokemon: Option<&str>,
+) -> Option<PokemonSummary>
+where
+    R: RepositoryProvider + Send + Sync,
+{
+    match option_partner_pokemon {
+        Some(partner_pokemon) => ctx.data.pokemon_summaries().get(ctx.data.pokedex(), partner_pokemon).await,
+        None => None,
+    }
+}
+
+/// Everything stored about a single Discord user, as returned by `/profile export`.
+#[derive(Serialize)]
+struct PersonalData {
+    exported_at: DateTime<Utc>,
+    discord_user_id: u64,
+    user_id: Option<i32>,
+    /// Every saved profile version, newest first.
+    profiles: Vec<PersonalDataProfile>,
+}
+
+#[derive(Serialize)]
+struct PersonalDataProfile {
+    id: i32,
+
+    thumbnail_url: Option<String>,
+    image_url: Option<String>,
+
+    trainer_class: Option<String>,
+    nature: Option<String>,
+    partner_pokemon: Option<String>,
+    starting_region: Option<String>,
+    favourite_food: Option<String>,
+    likes: Option<String>,
+    quotes: Option<String>,
+
+    pokemon_go_code: Option<String>,
+    pokemon_pocket_code: Option<String>,
+    switch_code: Option<String>,
+
+    created_at: DateTime<Utc>,
+    is_active: bool,
+}
+
+impl From<Profile> for PersonalDataProfile {
+    fn from(value: Profile) -> Self {
+        Self {
+            id: value.id,
+
+            thumbnail_url: value.thumbnail_url,
+            image_url: value.image_url,
+
+            trainer_class: value.trainer_class,
+            nature: value.nature,
+            partner_pokemon: value.partner_pokemon,
+            starting_region: value.starting_region,
+            favourite_food: value.favourite_food,
+            likes: value.likes,
+            quotes: value.quotes,
+
+            pokemon_go_code: value.pokemon_go_code,
+            pokemon_pocket_code: value.pokemon_pocket_code,
+            switch_code: value.switch_code,
+
+            created_at: value.created_at,
+            is_active: value.is_active,
+        }
+    }
+}
+
+/// Lists the fields that differ between two versions of a profile.
+///
+/// Each entry describes how to get from `active` to `version`, which is the change
+/// that restoring `version` would make.
+fn profile_diff(active: &Profile, version: &Profile) -> Vec<String> {
+    let fields = [
+        ("Thumbnail Image URL", &active.thumbnail_url, &version.thumbnail_url),
+        ("Footer Image URL", &active.image_url, &version.image_url),
+        ("Trainer Class", &active.trainer_class, &version.trainer_class),
+        ("Nature", &active.nature, &version.nature),
+        ("Pokémon", &active.partner_pokemon, &version.partner_pokemon),
+        ("Region", &active.starting_region, &version.starting_region),
+        ("Favourite Food", &active.favourite_food, &version.favourite_food),
+        ("Likes", &active.likes, &version.likes),
+        ("Quotes", &active.quotes, &version.quotes),
+        ("Pokémon Go Friend Code", &active.pokemon_go_code, &version.pokemon_go_code),
+        ("Pokémon TCG Pocket Friend Code", &active.pokemon_pocket_code, &version.pokemon_pocket_code),
+        ("Nintendo Switch Friend Code", &active.switch_code, &version.switch_code),
+    ];
+
+    fields.into_iter()
+        .filter(|(_, active_value, version_value)| active_value != version_value)
+        .map(|(name, active_value, version_value)| {
+            format!(
+                "**{}:** {} → {}",
+                name,
+                active_value.as_deref().unwrap_or("*empty*"),
+                version_value.as_deref().unwrap_or("*empty*"),
+            )
+        })
+        .collect()
+}
+
+/// Shortens a value to fit within the 1024 character limit of an embed field.
+fn truncate_field_value(mut value: String) -> String {
+    const MAX_FIELD_VALUE_CHARS: usize = 1024;
+
+    if value.chars().count() > MAX_FIELD_VALUE_CHARS {
+        value = value.chars().take(MAX_FIELD_VALUE_CHARS - 1).collect();
+        value.push('…');
+    }
+
+    value
+}
+
 #[derive(Debug, Clone, Default, poise::Modal)]
 #[name = "Edit Pokémon Information"]
 struct EditPokemonInfoModal {
@@ -709,6 +1657,98 @@ fn parse_switch_code(code: &str) -> Option<String> {
     chars.next().is_none().then_some(parsed)
 }
 
+/// The result of looking up a user provided name in PokéAPI.
+enum PokeApiNameLookup {
+    /// The name of a resource that matches the user provided name.
+    Found(String),
+    /// No resource matches. Contains the display names of similar resources.
+    NotFound(Vec<String>),
+    /// The resources could not be listed.
+    Unavailable,
+}
+
+impl EditPokemonInfoModal {
+    /// Checks the partner Pokémon, nature and region against PokéAPI and replaces them with their English names.
+    ///
+    /// Values are accepted unchanged if PokéAPI can not be reached.
+    async fn validate(&mut self, pokedex: &dyn PokedexSource) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        if let Some(partner_pokemon) = &self.partner_pokemon {
+            match lookup_pokeapi_name(partner_pokemon, pokedex.entries(Endpoint::PokemonSpecies).await) {
+                PokeApiNameLookup::Found(name) => {
+                    if let Ok(Some(species)) = pokedex.pokemon_species(&name).await {
+                        self.partner_pokemon = crate::pokeapi::english_name(&species.names).or(self.partner_pokemon.take());
+                    }
+                },
+                PokeApiNameLookup::NotFound(suggestions) => errors.push(unknown_name_error(partner_pokemon, "Pokémon", &suggestions)),
+                PokeApiNameLookup::Unavailable => {},
+            }
+        }
+
+        if let Some(nature) = &self.nature {
+            match lookup_pokeapi_name(nature, pokedex.entries(Endpoint::Nature).await) {
+                PokeApiNameLookup::Found(name) => {
+                    if let Ok(Some(nature)) = pokedex.nature(&name).await {
+                        self.nature = crate::pokeapi::english_name(&nature.names).or(self.nature.take());
+                    }
+                },
+                PokeApiNameLookup::NotFound(suggestions) => errors.push(unknown_name_error(nature, "nature", &suggestions)),
+                PokeApiNameLookup::Unavailable => {},
+            }
+        }
+
+        if let Some(starting_region) = &self.starting_region {
+            match lookup_pokeapi_name(starting_region, pokedex.entries(Endpoint::Region).await) {
+                PokeApiNameLookup::Found(name) => {
+                    if let Ok(Some(region)) = pokedex.region(&name).await {
+                        self.starting_region = crate::pokeapi::english_name(&region.names).or(self.starting_region.take());
+                    }
+                },
+                PokeApiNameLookup::NotFound(suggestions) => errors.push(unknown_name_error(starting_region, "region", &suggestions)),
+                PokeApiNameLookup::Unavailable => {},
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn lookup_pokeapi_name(value: &str, entries: Result<Arc<[PokedexEntry]>, PokedexError>) -> PokeApiNameLookup {
+    let entries = match entries {
+        Ok(entries) => entries,
+        Err(err) => {
+            log::warn!("Failed to list PokéAPI resources for validation: {}", err);
+            return PokeApiNameLookup::Unavailable;
+        },
+    };
+
+    let name = crate::pokeapi::pokeapi_name(value);
+
+    if entries.iter().any(|entry| entry.name == name) {
+        return PokeApiNameLookup::Found(name);
+    }
+
+    let suggestions = crate::utils::closest_matches(&name, entries.iter().map(|entry| entry.name.as_str()), 3)
+        .into_iter()
+        .map(crate::pokeapi::display_name)
+        .collect();
+
+    PokeApiNameLookup::NotFound(suggestions)
+}
+
+fn unknown_name_error(value: &str, kind: &str, suggestions: &[String]) -> String {
+    match suggestions {
+        [] => format!("`{}` is not a known {}.", value, kind),
+        [suggestion] => format!("`{}` is not a known {}. Did you mean {}?", value, kind, suggestion),
+        [suggestions @ .., last] => format!("`{}` is not a known {}. Did you mean {} or {}?", value, kind, suggestions.join(", "), last),
+    }
+}
+
 impl EditCodesModal {
     fn validate(&mut self) -> Result<(), Vec<String>> {
         let mut errors = Vec::new();
@@ -741,3 +1781,214 @@ impl EditCodesModal {
         }
     }
 }
+
+impl EditImagesModal {
+    async fn validate(&mut self, options: &ProfileImageOptions) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        if let Some(thumbnail_url) = &self.thumbnail_url {
+            match validate_image_url(thumbnail_url, options).await {
+                Ok(url) => self.thumbnail_url = Some(url.to_string()),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        if let Some(image_url) = &self.image_url {
+            match validate_image_url(image_url, options).await {
+                Ok(url) => self.image_url = Some(url.to_string()),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+async fn validate_image_url(value: &str, options: &ProfileImageOptions) -> Result<Url, String> {
+    let url = match Url::parse(value.trim()) {
+        Ok(url) => url,
+        Err(_) => return Err(format!("`{}` is not a valid URL.", value)),
+    };
+
+    if url.scheme() != "https" {
+        return Err(format!("`{}` must be an https link.", value));
+    }
+
+    let host = match url.host() {
+        Some(Host::Domain(host)) => host,
+        Some(Host::Ipv4(_) | Host::Ipv6(_)) => return Err(format!("`{}` must link to a domain name, not an IP address.", value)),
+        None => return Err(format!("`{}` is not a valid URL.", value)),
+    };
+
+    if !options.is_host_allowed(host) {
+        return Err(format!("`{}` is not hosted on an allowed site. Images can be linked from {}.", value, options.host_allowlist.join(", ")));
+    }
+
+    if options.probe_content_type {
+        // Only probe hosts on the public internet, so the probe can not be used to discover internal services.
+        let addresses: Vec<_> = match tokio::net::lookup_host((host, url.port_or_known_default().unwrap_or(443))).await {
+            Ok(addresses) => addresses.collect(),
+            Err(err) => {
+                log::debug!("Failed to resolve image URL {}: {}", url, err);
+                return Err(format!("`{}` could not be loaded.", value));
+            },
+        };
+
+        if addresses.is_empty() || !addresses.iter().all(|address| is_public_ip(address.ip())) {
+            return Err(format!("`{}` could not be loaded.", value));
+        }
+
+        // The probe connects to the checked addresses rather than resolving the host again, which could
+        // return an internal address the second time. Redirects are not followed for the same reason.
+        let http_client = match reqwest::Client::builder().redirect(Policy::none()).resolve_to_addrs(host, &addresses).build() {
+            Ok(http_client) => http_client,
+            Err(err) => {
+                log::error!("Failed to build the image probe client: {}", err);
+                return Err(format!("`{}` could not be loaded.", value));
+            },
+        };
+
+        let response = match http_client.head(url.clone()).timeout(IMAGE_PROBE_TIMEOUT).send().await {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => {
+                log::debug!("Probing image URL {} returned {}", url, response.status());
+                return Err(format!("`{}` could not be loaded.", value));
+            },
+            Err(err) => {
+                log::debug!("Failed to probe image URL {}: {}", url, err);
+                return Err(format!("`{}` could not be loaded.", value));
+            },
+        };
+
+        let is_image = response.headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .is_some_and(|content_type| content_type.trim().to_lowercase().starts_with("image/"));
+
+        if !is_image {
+            return Err(format!("`{}` does not link to an image.", value));
+        }
+    }
+
+    Ok(url)
+}
+
+/// Whether an address is reachable on the public internet, rather than being a
+/// loopback, private, link local or otherwise reserved address.
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            let is_shared = first == 100 && (64..128).contains(&second);
+
+            !(ip.is_unspecified() || ip.is_loopback() || ip.is_private() || ip.is_link_local()
+                || ip.is_broadcast() || ip.is_documentation() || ip.is_multicast() || is_shared)
+        },
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ip(IpAddr::V4(ip)),
+            None => {
+                let first_segment = ip.segments()[0];
+                let is_unique_local = first_segment & 0xfe00 == 0xfc00;
+                let is_link_local = first_segment & 0xffc0 == 0xfe80;
+
+                !(ip.is_unspecified() || ip.is_loopback() || ip.is_multicast() || is_unique_local || is_link_local)
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::cli::ProfileImageOptions;
+
+    use super::image_extension;
+    use super::is_public_ip;
+    use super::stored_image_path;
+    use super::validate_image_url;
+
+    fn storage_options() -> ProfileImageOptions {
+        ProfileImageOptions {
+            host_allowlist: Vec::new(),
+            probe_content_type: false,
+            storage_directory: Some(PathBuf::from("/srv/images")),
+            storage_base_url: Some("https://images.example.com/profiles/".parse().unwrap()),
+        }
+    }
+
+    #[tokio::test]
+    async fn validate_image_url_accepts_https_links_to_domains() {
+        let url = validate_image_url(" https://i.imgur.com/abc.png ", &storage_options()).await.unwrap();
+
+        assert_eq!(url.as_str(), "https://i.imgur.com/abc.png");
+    }
+
+    #[tokio::test]
+    async fn validate_image_url_rejects_other_links() {
+        let options = storage_options();
+
+        for value in ["not a url", "http://i.imgur.com/abc.png", "file:///etc/passwd", "https://127.0.0.1/abc.png", "https://[::1]/abc.png", "https://10.0.0.1/abc.png"] {
+            assert!(validate_image_url(value, &options).await.is_err(), "{} should be rejected", value);
+        }
+    }
+
+    #[tokio::test]
+    async fn validate_image_url_rejects_hosts_outside_the_allowlist() {
+        let options = ProfileImageOptions { host_allowlist: vec!["imgur.com".to_string()], ..storage_options() };
+
+        assert!(validate_image_url("https://i.imgur.com/abc.png", &options).await.is_ok());
+        assert!(validate_image_url("https://example.com/abc.png", &options).await.is_err());
+    }
+
+    #[test]
+    fn stored_image_path_finds_uploaded_images() {
+        let url = "https://images.example.com/profiles/67e55044-10b1-426f-9247-bb680e5fe0c8.png";
+
+        assert_eq!(stored_image_path(&storage_options(), url), Some(PathBuf::from("/srv/images/67e55044-10b1-426f-9247-bb680e5fe0c8.png")));
+    }
+
+    #[test]
+    fn stored_image_path_ignores_other_urls() {
+        let options = storage_options();
+
+        assert_eq!(stored_image_path(&options, "https://cdn.example.com/profiles/67e55044-10b1-426f-9247-bb680e5fe0c8.png"), None);
+        assert_eq!(stored_image_path(&options, "https://images.example.com/profiles/../secrets.png"), None);
+        assert_eq!(stored_image_path(&options, "https://images.example.com/profiles/67e55044-10b1-426f-9247-bb680e5fe0c8.html"), None);
+        assert_eq!(stored_image_path(&options, "https://images.example.com/profiles/nested/67e55044-10b1-426f-9247-bb680e5fe0c8.png"), None);
+
+        let unconfigured = ProfileImageOptions { storage_directory: None, ..storage_options() };
+        assert_eq!(stored_image_path(&unconfigured, "https://images.example.com/profiles/67e55044-10b1-426f-9247-bb680e5fe0c8.png"), None);
+    }
+
+    #[test]
+    fn image_extension_detects_allowed_formats() {
+        assert_eq!(image_extension(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"), Some("png"));
+        assert_eq!(image_extension(b"\xff\xd8\xff\xe0\0\x10JFIF"), Some("jpg"));
+        assert_eq!(image_extension(b"GIF89a\x01\0\x01\0"), Some("gif"));
+        assert_eq!(image_extension(b"RIFF\x24\0\0\0WEBPVP8 "), Some("webp"));
+    }
+
+    #[test]
+    fn image_extension_rejects_other_content() {
+        assert_eq!(image_extension(b""), None);
+        assert_eq!(image_extension(b"<svg xmlns=\"http://www.w3.org/2000/svg\"></svg>"), None);
+        assert_eq!(image_extension(b"<!DOCTYPE html><script>alert(1)</script>"), None);
+        assert_eq!(image_extension(b"RIFF\x24\0\0\0WAVEfmt "), None);
+    }
+
+    #[test]
+    fn is_public_ip_rejects_internal_addresses() {
+        for address in ["127.0.0.1", "10.0.0.1", "172.16.0.1", "192.168.1.1", "169.254.169.254", "100.64.0.1", "0.0.0.0", "::1", "fd00::1", "fe80::1", "::ffff:127.0.0.1"] {
+            assert!(!is_public_ip(address.parse().unwrap()), "{} should not be public", address);
+        }
+
+        for address in ["1.1.1.1", "162.159.128.233", "2606:4700::1111"] {
+            assert!(is_public_ip(address.parse().unwrap()), "{} should be public", address);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use cipher_core::repository::staff_role_repository::StaffRoleRepository;
+use cipher_core::repository::RepositoryProvider;
+use poise::CreateReply;
+use serenity::all::CreateEmbed;
+use serenity::all::Role;
+
+use crate::app::AppContext;
+use crate::app::AppError;
+
+/// Manage staff settings. Administrator only.
+#[poise::command(
+    slash_command,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR",
+    subcommands(
+        "role",
+    ),
+)]
+pub async fn staff<R: RepositoryProvider + Send + Sync>(
+    _ctx: AppContext<'_, R, R::BackendError>,
+) -> Result<(), AppError<R::BackendError>> {
+    Ok(())
+}
+
+/// Manage the roles that grant access to staff commands in this server.
+#[poise::command(
+    slash_command,
+    guild_only,
+    subcommands(
+        "add",
+        "list",
+        "remove",
+    ),
+)]
+async fn role<R: RepositoryProvider + Send + Sync>(
+    _ctx: AppContext<'_, R, R::BackendError>,
+) -> Result<(), AppError<R::BackendError>> {
+    Ok(())
+}
+
+/// Grant a role access to staff commands.
+#[poise::command(
+    slash_command,
+    guild_only,
+    check = "crate::checks::is_administrator",
+)]
+async fn add<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[description = "The role to grant staff access."] role: Role,
+) -> Result<(), AppError<R::BackendError>> {
+    let mut repo = ctx.data.repository().await?;
+
+    let description = match repo.is_staff_role(role.guild_id.get(), role.id.get()).await? {
+        true => format!("<@&{}> is already a staff role.", role.id),
+        false => {
+            repo.set_staff_role(role.guild_id.get(), role.id.get()).await?;
+            format!("<@&{}> is now a staff role.", role.id)
+        },
+    };
+
+    let embed = CreateEmbed::new()
+        .title("Staff Roles")
+        .description(description)
+        .color(crate::utils::bot_color(&ctx).await);
+
+    let reply = CreateReply::default()
+        .embed(embed)
+        .ephemeral(true);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+/// Revoke a role's access to staff commands.
+#[poise::command(
+    slash_command,
+    guild_only,
+    check = "crate::checks::is_administrator",
+)]
+async fn remove<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+    #[description = "The role to revoke staff access from."] role: Role,
+) -> Result<(), AppError<R::BackendError>> {
+    let mut repo = ctx.data.repository().await?;
+
+    let description = match repo.is_staff_role(role.guild_id.get(), role.id.get()).await? {
+        true => {
+            repo.unset_staff_role(role.guild_id.get(), role.id.get()).await?;
+
+            // Roles added before staff roles were scoped to servers apply in every server and are not removed here.
+            match repo.is_staff_role(role.guild_id.get(), role.id.get()).await? {
+                true => format!(
+                    "<@&{}> is still a staff role because it was added before staff roles were set per server, so it applies in every server. It can not be removed from within a server.",
+                    role.id,
+                ),
+                false => format!("<@&{}> is no longer a staff role.", role.id),
+            }
+        },
+        false => format!("<@&{}> is not a staff role.", role.id),
+    };
+
+    let embed = CreateEmbed::new()
+        .title("Staff Roles")
+        .description(description)
+        .color(crate::utils::bot_color(&ctx).await);
+
+    let reply = CreateReply::default()
+        .embed(embed)
+        .ephemeral(true);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+/// List the roles that grant access to staff commands.
+#[poise::command(
+    slash_command,
+    guild_only,
+    check = "crate::checks::is_administrator",
+)]
+async fn list<R: RepositoryProvider + Send + Sync>(
+    ctx: AppContext<'_, R, R::BackendError>,
+) -> Result<(), AppError<R::BackendError>> {
+    let guild_id = match ctx.guild_id() {
+        Some(guild_id) => guild_id,
+        None => return Ok(()),
+    };
+
+    let mut repo = ctx.data.repository().await?;
+
+    let staff_roles = repo.staff_roles(guild_id.get()).await?;
+
+    let mut embed_description = String::new();
+    for staff_role in &staff_roles {
+        embed_description.push_str(&format!("<@&{}>\n", staff_role));
+    }
+    embed_description.pop();
+
+    if embed_description.is_empty() {
+        embed_description.push_str("There are no staff roles. Use `/staff role add` to add one.");
+    }
+
+    let embed = CreateEmbed::new()
+        .title("Staff Roles")
+        .description(embed_description)
+        .color(crate::utils::bot_color(&ctx).await);
+
+    let reply = CreateReply::default()
+        .embed(embed)
+        .ephemeral(true);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
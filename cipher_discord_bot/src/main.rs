@@ -2,6 +2,8 @@ mod app;
 mod checks;
 mod cli;
 mod commands;
+mod paginator;
+mod pokeapi;
 mod utils;
 
 #[derive(Debug, thiserror::Error)]
@@ -9,7 +11,7 @@ enum MainError {
     #[error(transparent)]
     CliError(#[from] cli::CliError),
     #[error(transparent)]
-    CommandError(#[from] cli::command::CommandError)
+    CommandError(#[from] Box<cli::command::CommandError>),
 }
 
 #[tokio::main]
@@ -17,7 +19,8 @@ async fn main() -> Result<(), MainError> {
     let c = cli::parse()?;
     env_logger::init();
 
-    c.command.execute().await?;
+    // Command errors are boxed as they are much larger than the other errors.
+    c.command.execute().await.map_err(Box::new)?;
 
     Ok(())
 }
@@ -0,0 +1,492 @@
+use std::future::Future;
+use std::time::Duration;
+
+use cipher_core::repository::RepositoryProvider;
+use poise::CreateReply;
+use poise::ReplyHandle;
+use serenity::all::ButtonStyle;
+use serenity::all::ComponentInteraction;
+use serenity::all::ComponentInteractionCollector;
+use serenity::all::ComponentInteractionDataKind;
+use serenity::all::CreateActionRow;
+use serenity::all::CreateButton;
+use serenity::all::CreateEmbed;
+use serenity::all::CreateInteractionResponse;
+use serenity::all::CreateSelectMenu;
+use serenity::all::CreateSelectMenuKind;
+use serenity::all::CreateSelectMenuOption;
+use serenity::all::UserId;
+use uuid::Uuid;
+
+use crate::app::AppContext;
+use crate::app::AppError;
+
+/// The time a paginator waits for an interaction by default.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The maximum number of options Discord accepts in a select menu.
+const SELECT_MENU_OPTION_LIMIT: usize = 25;
+
+/// A page rendered by a [`Paginator`], with optional buttons that are handled by the command itself.
+pub struct Page {
+    embed: CreateEmbed,
+    actions: Vec<(String, CreateButton)>,
+}
+
+impl Page {
+    pub fn new(embed: CreateEmbed) -> Self {
+        Self {
+            embed,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Adds a button below the page navigation. Pressing it ends [`Paginator::run`] with
+    /// [`PaginatorOutcome::Action`]. The button must have been created with `custom_id`.
+    pub fn action(mut self, custom_id: impl Into<String>, button: CreateButton) -> Self {
+        self.actions.push((custom_id.into(), button));
+        self
+    }
+}
+
+impl From<CreateEmbed> for Page {
+    fn from(embed: CreateEmbed) -> Self {
+        Self::new(embed)
+    }
+}
+
+pub enum PaginatorOutcome {
+    /// No interaction was received before the timeout. The components have been removed from the reply.
+    TimedOut,
+    /// One of the action buttons of the page was pressed. The interaction has not been responded to.
+    Action {
+        custom_id: String,
+        interaction: Box<ComponentInteraction>,
+    },
+}
+
+/// Shows pages of embeds in a single reply, with buttons to go to the first, previous, next and last page
+/// and a select menu to jump to a page.
+///
+/// Pages are rendered on demand by a closure, so only the shown page has to be loaded. A paginator keeps
+/// its reply and current page between calls of [`Paginator::run`], so commands with action buttons can
+/// handle an action and continue paging in the same reply.
+pub struct Paginator<'a> {
+    page_count: usize,
+    page_number: usize,
+    page_labels: Vec<String>,
+    timeout: Duration,
+    author_only: bool,
+    ephemeral: bool,
+    disable_while_rendering: bool,
+    option_reply_handle: Option<ReplyHandle<'a>>,
+}
+
+impl<'a> Paginator<'a> {
+    pub fn new(page_count: usize) -> Self {
+        Self {
+            page_count: page_count.max(1),
+            page_number: 1,
+            page_labels: Vec::new(),
+            timeout: DEFAULT_TIMEOUT,
+            author_only: true,
+            ephemeral: true,
+            disable_while_rendering: false,
+            option_reply_handle: None,
+        }
+    }
+
+    /// The page to show next, starting at 1. Out of range page numbers are clamped.
+    pub fn page_number(mut self, page_number: usize) -> Self {
+        self.set_page_number(page_number);
+        self
+    }
+
+    /// Names of the pages shown in the jump to page menu instead of their page numbers.
+    pub fn page_labels(mut self, page_labels: Vec<String>) -> Self {
+        self.page_labels = page_labels;
+        self
+    }
+
+    /// How long to wait for an interaction before removing the components. Default is 60 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Whether only the user who used the command can change pages. Default is true.
+    pub fn author_only(mut self, author_only: bool) -> Self {
+        self.author_only = author_only;
+        self
+    }
+
+    /// Whether the reply is hidden from other users. Default is true.
+    pub fn ephemeral(mut self, ephemeral: bool) -> Self {
+        self.ephemeral = ephemeral;
+        self
+    }
+
+    /// Whether to disable the components of the current page while the next page is rendered,
+    /// for pages that take a noticeable time to load. Default is false.
+    pub fn disable_while_rendering(mut self, disable_while_rendering: bool) -> Self {
+        self.disable_while_rendering = disable_while_rendering;
+        self
+    }
+
+    pub fn current_page_number(&self) -> usize {
+        self.page_number
+    }
+
+    pub fn set_page_number(&mut self, page_number: usize) {
+        self.page_number = page_number.clamp(1, self.page_count);
+    }
+
+    pub fn set_page_count(&mut self, page_count: usize) {
+        self.page_count = page_count.max(1);
+        self.page_number = self.page_number.min(self.page_count);
+    }
+
+    /// Shows pages rendered by `render` until the timeout expires or an action button is pressed.
+    pub async fn run<R, F, Fut, P>(
+        &mut self,
+        ctx: AppContext<'a, R, R::BackendError>,
+        mut render: F,
+    ) -> Result<PaginatorOutcome, AppError<R::BackendError>>
+    where
+        R: RepositoryProvider + Send + Sync,
+        F: FnMut(usize) -> Fut,
+        Fut: Future<Output = Result<P, AppError<R::BackendError>>>,
+        P: Into<Page>,
+    {
+        loop {
+            let page: Page = render(self.page_number).await?.into();
+
+            let navigation_ids = NavigationIds::new();
+            let (action_ids, action_buttons): (Vec<_>, Vec<_>) = page.actions.into_iter().unzip();
+
+            let reply = CreateReply::default()
+                .embed(page.embed.clone())
+                .components(self.components(&navigation_ids, &action_buttons, false))
+                .ephemeral(self.ephemeral);
+
+            self.show(ctx, reply).await?;
+
+            let mut custom_ids = navigation_ids.all();
+            custom_ids.extend(action_ids.iter().cloned());
+
+            let collector_options = self.collector_options(ctx.author().id);
+
+            let mut collector = ComponentInteractionCollector::new(ctx)
+                .channel_id(ctx.channel_id())
+                .custom_ids(custom_ids)
+                .timeout(collector_options.timeout);
+
+            if let Some(author_id) = collector_options.option_author_id {
+                collector = collector.author_id(author_id);
+            }
+
+            let mci = match collector.await {
+                Some(mci) => mci,
+                None => {
+                    let reply = CreateReply::default()
+                        .embed(page.embed)
+                        .components(vec![])
+                        .ephemeral(self.ephemeral);
+
+                    self.show(ctx, reply).await?;
+
+                    return Ok(PaginatorOutcome::TimedOut);
+                },
+            };
+
+            if action_ids.contains(&mci.data.custom_id) {
+                return Ok(PaginatorOutcome::Action {
+                    custom_id: mci.data.custom_id.clone(),
+                    interaction: Box::new(mci),
+                });
+            }
+
+            let custom_id = mci.data.custom_id.as_str();
+
+            if custom_id == navigation_ids.jump_button {
+                // The modal responds to the interaction itself.
+                let option_data = poise::execute_modal_on_component_interaction::<JumpToPageModal>(ctx, mci.clone(), None, Some(collector_options.timeout)).await?;
+
+                if let Some(page_number) = option_data.and_then(|data| data.page_number.trim().parse::<usize>().ok()) {
+                    self.set_page_number(page_number);
+                }
+
+                continue;
+            }
+
+            if custom_id == navigation_ids.first_button {
+                self.set_page_number(1);
+            }
+
+            if custom_id == navigation_ids.previous_button {
+                self.set_page_number(self.page_number.saturating_sub(1));
+            }
+
+            if custom_id == navigation_ids.next_button {
+                self.set_page_number(self.page_number + 1);
+            }
+
+            if custom_id == navigation_ids.last_button {
+                self.set_page_number(self.page_count);
+            }
+
+            if custom_id == navigation_ids.select_menu {
+                if let ComponentInteractionDataKind::StringSelect { values } = &mci.data.kind {
+                    if let Some(page_number) = values.first().and_then(|value| value.parse().ok()) {
+                        self.set_page_number(page_number);
+                    }
+                }
+            }
+
+            mci.create_response(ctx, CreateInteractionResponse::Acknowledge).await?;
+
+            if self.disable_while_rendering {
+                let reply = CreateReply::default()
+                    .embed(page.embed)
+                    .components(self.components(&navigation_ids, &action_buttons, true))
+                    .ephemeral(self.ephemeral);
+
+                self.show(ctx, reply).await?;
+            }
+        }
+    }
+
+    /// Replaces the reply of the paginator, e.g. with a confirmation after an action.
+    pub async fn edit<R>(&self, ctx: AppContext<'a, R, R::BackendError>, reply: CreateReply) -> Result<(), AppError<R::BackendError>>
+    where
+        R: RepositoryProvider + Send + Sync,
+    {
+        match &self.option_reply_handle {
+            Some(reply_handle) => reply_handle.edit(ctx.into(), reply).await?,
+            None => {
+                ctx.send(reply).await?;
+            },
+        }
+
+        Ok(())
+    }
+
+    async fn show<R>(&mut self, ctx: AppContext<'a, R, R::BackendError>, reply: CreateReply) -> Result<(), AppError<R::BackendError>>
+    where
+        R: RepositoryProvider + Send + Sync,
+    {
+        match &self.option_reply_handle {
+            Some(reply_handle) => reply_handle.edit(ctx.into(), reply).await?,
+            None => self.option_reply_handle = Some(ctx.send(reply).await?),
+        }
+
+        Ok(())
+    }
+
+    /// The filters applied to the interactions collected for a reply to `author_id`.
+    fn collector_options(&self, author_id: UserId) -> CollectorOptions {
+        CollectorOptions {
+            timeout: self.timeout,
+            option_author_id: self.author_only.then_some(author_id),
+        }
+    }
+
+    fn components(&self, navigation_ids: &NavigationIds, action_buttons: &[CreateButton], disabled: bool) -> Vec<CreateActionRow> {
+        let mut components = Vec::new();
+
+        if self.page_count > 1 {
+            let mut buttons = vec![
+                CreateButton::new(&navigation_ids.first_button).label("First").style(ButtonStyle::Secondary).disabled(disabled || self.page_number <= 1),
+                CreateButton::new(&navigation_ids.previous_button).label("Previous").style(ButtonStyle::Secondary).disabled(disabled || self.page_number <= 1),
+                CreateButton::new(&navigation_ids.next_button).label("Next").style(ButtonStyle::Secondary).disabled(disabled || self.page_number >= self.page_count),
+                CreateButton::new(&navigation_ids.last_button).label("Last").style(ButtonStyle::Secondary).disabled(disabled || self.page_number >= self.page_count),
+            ];
+
+            // The select menu only fits the pages around the current page.
+            if self.page_count > SELECT_MENU_OPTION_LIMIT {
+                buttons.push(CreateButton::new(&navigation_ids.jump_button).label("Jump To Page").style(ButtonStyle::Secondary).disabled(disabled));
+            }
+
+            components.push(CreateActionRow::Buttons(buttons));
+
+            let lower = self.page_number
+                .saturating_sub(SELECT_MENU_OPTION_LIMIT / 2)
+                .clamp(1, (self.page_count + 1).saturating_sub(SELECT_MENU_OPTION_LIMIT).max(1));
+            let upper = (lower + SELECT_MENU_OPTION_LIMIT - 1).min(self.page_count);
+
+            let options = (lower..=upper)
+                .map(|page_number| {
+                    let label = match self.page_labels.get(page_number - 1) {
+                        Some(label) => label.clone(),
+                        None => format!("Page {}", page_number),
+                    };
+
+                    CreateSelectMenuOption::new(label, page_number.to_string())
+                        .default_selection(page_number == self.page_number)
+                })
+                .collect();
+
+            let select_menu = CreateSelectMenu::new(&navigation_ids.select_menu, CreateSelectMenuKind::String { options })
+                .placeholder("Jump to page")
+                .disabled(disabled);
+
+            components.push(CreateActionRow::SelectMenu(select_menu));
+        }
+
+        if !action_buttons.is_empty() {
+            let buttons = action_buttons.iter()
+                .map(|button| match disabled {
+                    true => button.clone().disabled(true),
+                    false => button.clone(),
+                })
+                .collect();
+
+            components.push(CreateActionRow::Buttons(buttons));
+        }
+
+        components
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CollectorOptions {
+    timeout: Duration,
+    /// The only user whose interactions are collected, or `None` to collect those of every user.
+    option_author_id: Option<UserId>,
+}
+
+/// Custom ids of the navigation components, unique to each rendered page so that
+/// interactions with earlier replies are ignored.
+struct NavigationIds {
+    first_button: String,
+    previous_button: String,
+    next_button: String,
+    last_button: String,
+    jump_button: String,
+    select_menu: String,
+}
+
+impl NavigationIds {
+    fn new() -> Self {
+        Self {
+            first_button: Uuid::new_v4().to_string(),
+            previous_button: Uuid::new_v4().to_string(),
+            next_button: Uuid::new_v4().to_string(),
+            last_button: Uuid::new_v4().to_string(),
+            jump_button: Uuid::new_v4().to_string(),
+            select_menu: Uuid::new_v4().to_string(),
+        }
+    }
+
+    fn all(&self) -> Vec<String> {
+        vec![
+            self.first_button.clone(),
+            self.previous_button.clone(),
+            self.next_button.clone(),
+            self.last_button.clone(),
+            self.jump_button.clone(),
+            self.select_menu.clone(),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Default, poise::Modal)]
+#[name = "Jump To Page"]
+struct JumpToPageModal {
+    #[name = "Page"]
+    #[placeholder = "The number of the page to show"]
+    page_number: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serenity::all::UserId;
+
+    use super::CollectorOptions;
+    use super::NavigationIds;
+    use super::Paginator;
+    use super::DEFAULT_TIMEOUT;
+
+    /// The page numbers offered by the select menu of the paginator, or `None` if it has no select menu.
+    fn select_menu_page_numbers(paginator: &Paginator) -> Option<Vec<usize>> {
+        let components = serde_json::to_value(paginator.components(&NavigationIds::new(), &[], false)).unwrap();
+
+        components.as_array().unwrap().iter()
+            .flat_map(|row| row["components"].as_array().unwrap())
+            .find(|component| component.get("options").is_some())
+            .map(|select_menu| {
+                select_menu["options"].as_array().unwrap().iter()
+                    .map(|option| option["value"].as_str().unwrap().parse().unwrap())
+                    .collect()
+            })
+    }
+
+    #[test]
+    fn page_count_is_at_least_one() {
+        let paginator = Paginator::new(0);
+
+        assert_eq!(paginator.page_count, 1);
+        assert_eq!(paginator.current_page_number(), 1);
+    }
+
+    #[test]
+    fn page_number_is_clamped() {
+        assert_eq!(Paginator::new(5).page_number(0).current_page_number(), 1);
+        assert_eq!(Paginator::new(5).page_number(3).current_page_number(), 3);
+        assert_eq!(Paginator::new(5).page_number(9).current_page_number(), 5);
+    }
+
+    #[test]
+    fn shrinking_the_page_count_clamps_the_page_number() {
+        let mut paginator = Paginator::new(10).page_number(8);
+
+        paginator.set_page_count(4);
+        assert_eq!(paginator.current_page_number(), 4);
+
+        paginator.set_page_count(0);
+        assert_eq!(paginator.current_page_number(), 1);
+    }
+
+    #[test]
+    fn collector_defaults_to_the_author_and_default_timeout() {
+        let author_id = UserId::new(1);
+
+        assert_eq!(Paginator::new(1).collector_options(author_id), CollectorOptions {
+            timeout: DEFAULT_TIMEOUT,
+            option_author_id: Some(author_id),
+        });
+    }
+
+    #[test]
+    fn collector_uses_the_builder_options() {
+        let paginator = Paginator::new(1)
+            .timeout(Duration::from_secs(300))
+            .author_only(false);
+
+        assert_eq!(paginator.collector_options(UserId::new(1)), CollectorOptions {
+            timeout: Duration::from_secs(300),
+            option_author_id: None,
+        });
+    }
+
+    #[test]
+    fn single_page_has_no_navigation() {
+        assert_eq!(select_menu_page_numbers(&Paginator::new(1)), None);
+    }
+
+    #[test]
+    fn select_menu_lists_every_page_when_they_fit() {
+        assert_eq!(select_menu_page_numbers(&Paginator::new(3)), Some(vec![1, 2, 3]));
+        assert_eq!(select_menu_page_numbers(&Paginator::new(25).page_number(25)), Some((1..=25).collect()));
+    }
+
+    #[test]
+    fn select_menu_window_stays_within_the_pages() {
+        assert_eq!(select_menu_page_numbers(&Paginator::new(100)), Some((1..=25).collect()));
+        assert_eq!(select_menu_page_numbers(&Paginator::new(100).page_number(50)), Some((38..=62).collect()));
+        assert_eq!(select_menu_page_numbers(&Paginator::new(100).page_number(100)), Some((76..=100).collect()));
+        assert_eq!(select_menu_page_numbers(&Paginator::new(26).page_number(26)), Some((2..=26).collect()));
+    }
+}
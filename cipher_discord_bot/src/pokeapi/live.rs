@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use rustemon::client::CACacheManager;
+use rustemon::client::CacheMode;
+use rustemon::client::RustemonClient;
+use rustemon::client::RustemonClientBuilder;
+use rustemon::model::evolution::EvolutionChain;
+use rustemon::model::items::Item;
+use rustemon::model::locations::Region;
+use rustemon::model::moves::Move;
+use rustemon::model::pokemon::Ability;
+use rustemon::model::pokemon::Nature;
+use rustemon::model::pokemon::Pokemon;
+use rustemon::model::pokemon::PokemonSpecies;
+use rustemon::model::pokemon::Type;
+use rustemon::model::resource::NamedApiResource;
+
+use crate::cli::PokeApiOptions;
+
+use super::resource_id;
+use super::Endpoint;
+use super::PokedexEntry;
+use super::PokedexError;
+use super::PokedexSource;
+
+/// The longest time between two sweeps of expired entries from the PokéAPI response cache.
+const CACHE_EXPIRY_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// A resource list along with the time it was fetched.
+type CachedEntries = (Instant, Arc<[PokedexEntry]>);
+
+/// Reads Pokédex data from PokéAPI over the network.
+///
+/// Responses are cached on disk and served from the cache regardless of the
+/// caching headers sent by PokéAPI, until they are removed by [`spawn_cache_expiry`].
+/// Resource lists are also kept in memory, as they are read on every autocomplete request.
+pub struct LivePokedex {
+    rustemon_client: RustemonClient,
+    entries_ttl: Duration,
+    entries: Mutex<HashMap<Endpoint, CachedEntries>>,
+}
+
+impl LivePokedex {
+    pub fn new(options: &PokeApiOptions) -> Result<Self, rustemon::error::Error> {
+        let rustemon_client = RustemonClientBuilder::default()
+            .with_manager(CACacheManager { path: options.cache_directory.clone() })
+            .with_mode(CacheMode::ForceCache)
+            .try_build()?;
+
+        Ok(Self {
+            rustemon_client,
+            entries_ttl: options.cache_ttl,
+            entries: Mutex::new(HashMap::new()),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl PokedexSource for LivePokedex {
+    async fn entries(&self, endpoint: Endpoint) -> Result<Arc<[PokedexEntry]>, PokedexError> {
+        if let Some((fetched_at, entries)) = self.entries.lock().unwrap_or_else(PoisonError::into_inner).get(&endpoint) {
+            if fetched_at.elapsed() < self.entries_ttl {
+                return Ok(entries.clone());
+            }
+        }
+
+        let client = &self.rustemon_client;
+
+        let entries = match endpoint {
+            Endpoint::Pokemon => into_entries(rustemon::pokemon::pokemon::get_all_entries(client).await?),
+            Endpoint::PokemonSpecies => into_entries(rustemon::pokemon::pokemon_species::get_all_entries(client).await?),
+            Endpoint::Nature => into_entries(rustemon::pokemon::nature::get_all_entries(client).await?),
+            Endpoint::Region => into_entries(rustemon::locations::region::get_all_entries(client).await?),
+            Endpoint::Move => into_entries(rustemon::moves::move_::get_all_entries(client).await?),
+            Endpoint::Ability => into_entries(rustemon::pokemon::ability::get_all_entries(client).await?),
+            Endpoint::Item => into_entries(rustemon::items::item::get_all_entries(client).await?),
+            Endpoint::Type => into_entries(rustemon::pokemon::type_::get_all_entries(client).await?),
+        };
+
+        let entries: Arc<[PokedexEntry]> = Arc::from(entries);
+
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner).insert(endpoint, (Instant::now(), entries.clone()));
+
+        Ok(entries)
+    }
+
+    async fn pokemon(&self, name: &str) -> Result<Option<Pokemon>, PokedexError> {
+        not_found_as_none(rustemon::pokemon::pokemon::get_by_name(name, &self.rustemon_client).await)
+    }
+
+    async fn pokemon_species(&self, name: &str) -> Result<Option<PokemonSpecies>, PokedexError> {
+        not_found_as_none(rustemon::pokemon::pokemon_species::get_by_name(name, &self.rustemon_client).await)
+    }
+
+    async fn nature(&self, name: &str) -> Result<Option<Nature>, PokedexError> {
+        not_found_as_none(rustemon::pokemon::nature::get_by_name(name, &self.rustemon_client).await)
+    }
+
+    async fn region(&self, name: &str) -> Result<Option<Region>, PokedexError> {
+        not_found_as_none(rustemon::locations::region::get_by_name(name, &self.rustemon_client).await)
+    }
+
+    async fn pokemon_move(&self, name: &str) -> Result<Option<Move>, PokedexError> {
+        not_found_as_none(rustemon::moves::move_::get_by_name(name, &self.rustemon_client).await)
+    }
+
+    async fn ability(&self, name: &str) -> Result<Option<Ability>, PokedexError> {
+        not_found_as_none(rustemon::pokemon::ability::get_by_name(name, &self.rustemon_client).await)
+    }
+
+    async fn item(&self, name: &str) -> Result<Option<Item>, PokedexError> {
+        not_found_as_none(rustemon::items::item::get_by_name(name, &self.rustemon_client).await)
+    }
+
+    async fn pokemon_type(&self, name: &str) -> Result<Option<Type>, PokedexError> {
+        not_found_as_none(rustemon::pokemon::type_::get_by_name(name, &self.rustemon_client).await)
+    }
+
+    async fn evolution_chain(&self, id: i64) -> Result<Option<EvolutionChain>, PokedexError> {
+        not_found_as_none(rustemon::evolution::evolution_chain::get_by_id(id, &self.rustemon_client).await)
+    }
+}
+
+fn into_entries<T>(resources: Vec<NamedApiResource<T>>) -> Vec<PokedexEntry> {
+    let mut entries: Vec<_> = resources.into_iter()
+        .filter_map(|resource| {
+            resource_id(&resource.url).map(|id| PokedexEntry { id, name: resource.name })
+        })
+        .collect();
+
+    entries.sort_by_key(|entry| entry.id);
+    entries
+}
+
+fn not_found_as_none<T>(result: Result<T, rustemon::error::Error>) -> Result<Option<T>, PokedexError> {
+    match result {
+        Ok(resource) => Ok(Some(resource)),
+        // PokéAPI answers unknown names with a body that is not a resource.
+        Err(rustemon::error::Error::Reqwest(err)) if err.is_decode() => Ok(None),
+        Err(err) => Err(PokedexError::from(err)),
+    }
+}
+
+/// Periodically removes cached PokéAPI responses that are older than the configured time to live.
+pub fn spawn_cache_expiry(options: &PokeApiOptions) {
+    let cache_directory = options.cache_directory.clone();
+    let ttl = options.cache_ttl;
+    let interval = ttl.min(CACHE_EXPIRY_INTERVAL);
+
+    tokio::spawn(async move {
+        loop {
+            let cache_directory = cache_directory.clone();
+            match tokio::task::spawn_blocking(move || remove_expired_cache_entries(&cache_directory, ttl)).await {
+                Ok(Ok(0)) => {},
+                Ok(Ok(removed)) => log::debug!("Removed {} expired PokéAPI responses from the cache.", removed),
+                Ok(Err(err)) => log::warn!("Failed to remove expired PokéAPI responses from the cache: {}", err),
+                Err(err) => log::warn!("Failed to remove expired PokéAPI responses from the cache: {}", err),
+            }
+
+            tokio::time::sleep(interval).await;
+        }
+    });
+}
+
+fn remove_expired_cache_entries(cache_directory: &Path, ttl: Duration) -> Result<usize, cacache::Error> {
+    // The cache is created by the first request, there is nothing to expire before then.
+    if !cache_directory.exists() {
+        return Ok(0);
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+
+    let mut removed = 0;
+    for metadata in cacache::list_sync(cache_directory) {
+        let metadata = metadata?;
+
+        if now.saturating_sub(metadata.time) > ttl.as_millis() {
+            cacache::RemoveOpts::new()
+                .remove_fully(true)
+                .remove_sync(cache_directory, &metadata.key)?;
+            removed += 1;
+        }
+    }
+
+    Ok(removed)
+}
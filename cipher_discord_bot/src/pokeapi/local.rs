@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+use rustemon::model::evolution::EvolutionChain;
+use rustemon::model::items::Item;
+use rustemon::model::locations::Region;
+use rustemon::model::moves::Move;
+use rustemon::model::pokemon::Ability;
+use rustemon::model::pokemon::Nature;
+use rustemon::model::pokemon::Pokemon;
+use rustemon::model::pokemon::PokemonSpecies;
+use rustemon::model::pokemon::Type;
+use rustemon::model::resource::NamedApiResourceList;
+use serde::de::DeserializeOwned;
+
+use super::resource_id;
+use super::Endpoint;
+use super::PokedexEntry;
+use super::PokedexError;
+use super::PokedexSource;
+
+/// Reads Pokédex data from a local PokéAPI JSON dump.
+///
+/// The dump uses the layout of the PokeAPI/api-data repository, where the resource list of an
+/// endpoint is stored in `api/v2/{endpoint}/index.json` and each resource is stored in
+/// `api/v2/{endpoint}/{id}/index.json`.
+pub struct LocalPokedex {
+    directory: PathBuf,
+    entries: Mutex<HashMap<Endpoint, Arc<[PokedexEntry]>>>,
+}
+
+impl LocalPokedex {
+    pub fn new(directory: PathBuf) -> Self {
+        Self {
+            directory,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn cached_entries(&self, endpoint: Endpoint) -> Result<Arc<[PokedexEntry]>, PokedexError> {
+        if let Some(entries) = self.entries.lock().unwrap_or_else(PoisonError::into_inner).get(&endpoint) {
+            return Ok(entries.clone());
+        }
+
+        let path = self.directory.join("api/v2").join(endpoint.path()).join("index.json");
+        let list: NamedApiResourceList<()> = serde_json::from_slice(&tokio::fs::read(path).await?)?;
+
+        let mut entries: Vec<_> = list.results
+            .into_iter()
+            .filter_map(|resource| {
+                resource_id(&resource.url).map(|id| PokedexEntry { id, name: resource.name })
+            })
+            .collect();
+
+        entries.sort_by_key(|entry| entry.id);
+
+        let entries: Arc<[PokedexEntry]> = Arc::from(entries);
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner).insert(endpoint, entries.clone());
+
+        Ok(entries)
+    }
+
+    async fn resource<T>(&self, endpoint: Endpoint, name: &str) -> Result<Option<T>, PokedexError>
+    where
+        T: DeserializeOwned,
+    {
+        let entries = self.cached_entries(endpoint).await?;
+
+        match entries.iter().find(|entry| entry.name == name) {
+            Some(entry) => self.resource_by_id(endpoint.path(), entry.id).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn resource_by_id<T>(&self, endpoint_path: &str, id: i64) -> Result<Option<T>, PokedexError>
+    where
+        T: DeserializeOwned,
+    {
+        let path = self.directory.join("api/v2").join(endpoint_path).join(id.to_string()).join("index.json");
+
+        let bytes = match tokio::fs::read(path).await {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(PokedexError::from(err)),
+        };
+
+        Ok(Some(serde_json::from_slice(&bytes)?))
+    }
+}
+
+#[async_trait::async_trait]
+impl PokedexSource for LocalPokedex {
+    async fn entries(&self, endpoint: Endpoint) -> Result<Arc<[PokedexEntry]>, PokedexError> {
+        self.cached_entries(endpoint).await
+    }
+
+    async fn pokemon(&self, name: &str) -> Result<Option<Pokemon>, PokedexError> {
+        self.resource(Endpoint::Pokemon, name).await
+    }
+
+    async fn pokemon_species(&self, name: &str) -> Result<Option<PokemonSpecies>, PokedexError> {
+        self.resource(Endpoint::PokemonSpecies, name).await
+    }
+
+    async fn nature(&self, name: &str) -> Result<Option<Nature>, PokedexError> {
+        self.resource(Endpoint::Nature, name).await
+    }
+
+    async fn region(&self, name: &str) -> Result<Option<Region>, PokedexError> {
+        self.resource(Endpoint::Region, name).await
+    }
+
+    async fn pokemon_move(&self, name: &str) -> Result<Option<Move>, PokedexError> {
+        self.resource(Endpoint::Move, name).await
+    }
+
+    async fn ability(&self, name: &str) -> Result<Option<Ability>, PokedexError> {
+        self.resource(Endpoint::Ability, name).await
+    }
+
+    async fn item(&self, name: &str) -> Result<Option<Item>, PokedexError> {
+        self.resource(Endpoint::Item, name).await
+    }
+
+    async fn pokemon_type(&self, name: &str) -> Result<Option<Type>, PokedexError> {
+        self.resource(Endpoint::Type, name).await
+    }
+
+    async fn evolution_chain(&self, id: i64) -> Result<Option<EvolutionChain>, PokedexError> {
+        self.resource_by_id("evolution-chain", id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::path::PathBuf;
+
+    use uuid::Uuid;
+
+    use super::LocalPokedex;
+    use crate::pokeapi::Endpoint;
+    use crate::pokeapi::PokedexEntry;
+    use crate::pokeapi::PokedexSource;
+
+    /// A PokéAPI dump in a temporary directory, removed when dropped.
+    struct Fixture {
+        directory: PathBuf,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let directory = std::env::temp_dir().join(format!("cipher-local-pokedex-{}", Uuid::new_v4()));
+
+            // Listed out of id order to check that entries are sorted.
+            write_json(&directory, "region/index.json", r#"{
+                "count": 3,
+                "next": null,
+                "previous": null,
+                "results": [
+                    { "name": "johto", "url": "https://pokeapi.co/api/v2/region/2/" },
+                    { "name": "kanto", "url": "https://pokeapi.co/api/v2/region/1/" },
+                    { "name": "hoenn", "url": "https://pokeapi.co/api/v2/region/3/" }
+                ]
+            }"#);
+
+            write_json(&directory, "region/1/index.json", r#"{
+                "id": 1,
+                "locations": [],
+                "name": "kanto",
+                "names": [{ "name": "Kanto", "language": { "name": "en", "url": "https://pokeapi.co/api/v2/language/9/" } }],
+                "main_generation": { "name": "generation-i", "url": "https://pokeapi.co/api/v2/generation/1/" },
+                "pokedexes": [],
+                "version_groups": []
+            }"#);
+
+            write_json(&directory, "evolution-chain/10/index.json", r#"{
+                "id": 10,
+                "baby_trigger_item": null,
+                "chain": {
+                    "is_baby": false,
+                    "species": { "name": "pichu", "url": "https://pokeapi.co/api/v2/pokemon-species/172/" },
+                    "evolution_details": [],
+                    "evolves_to": [{
+                        "is_baby": false,
+                        "species": { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon-species/25/" },
+                        "evolution_details": [],
+                        "evolves_to": []
+                    }]
+                }
+            }"#);
+
+            Self { directory }
+        }
+
+        fn pokedex(&self) -> LocalPokedex {
+            LocalPokedex::new(self.directory.clone())
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.directory);
+        }
+    }
+
+    fn write_json(directory: &Path, path: &str, json: &str) {
+        let path = directory.join("api/v2").join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, json).unwrap();
+    }
+
+    #[tokio::test]
+    async fn entries_are_ordered_by_id() {
+        let fixture = Fixture::new();
+
+        let entries = fixture.pokedex().entries(Endpoint::Region).await.unwrap();
+
+        assert_eq!(entries.to_vec(), vec![
+            PokedexEntry { id: 1, name: "kanto".to_string() },
+            PokedexEntry { id: 2, name: "johto".to_string() },
+            PokedexEntry { id: 3, name: "hoenn".to_string() },
+        ]);
+    }
+
+    #[tokio::test]
+    async fn entries_of_a_missing_endpoint_are_an_error() {
+        let fixture = Fixture::new();
+
+        assert!(fixture.pokedex().entries(Endpoint::Item).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn resources_are_found_by_name() {
+        let fixture = Fixture::new();
+
+        let region = fixture.pokedex().region("kanto").await.unwrap().unwrap();
+
+        assert_eq!(region.id, 1);
+        assert_eq!(region.name, "kanto");
+    }
+
+    #[tokio::test]
+    async fn unknown_names_and_missing_ids_are_none() {
+        let fixture = Fixture::new();
+        let pokedex = fixture.pokedex();
+
+        // Unknown to the resource list.
+        assert!(pokedex.region("galar").await.unwrap().is_none());
+        // Listed, but missing from the dump.
+        assert!(pokedex.region("johto").await.unwrap().is_none());
+        assert!(pokedex.evolution_chain(11).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn evolution_chains_are_found_by_id() {
+        let fixture = Fixture::new();
+
+        let evolution_chain = fixture.pokedex().evolution_chain(10).await.unwrap().unwrap();
+
+        assert_eq!(evolution_chain.chain.species.name, "pichu");
+        assert_eq!(evolution_chain.chain.evolves_to[0].species.name, "pikachu");
+    }
+}
@@ -0,0 +1,173 @@
+use std::sync::Arc;
+
+use rustemon::model::evolution::EvolutionChain;
+use rustemon::model::items::Item;
+use rustemon::model::locations::Region;
+use rustemon::model::moves::Move;
+use rustemon::model::pokemon::Ability;
+use rustemon::model::pokemon::Nature;
+use rustemon::model::pokemon::Pokemon;
+use rustemon::model::pokemon::PokemonSpecies;
+use rustemon::model::pokemon::Type;
+use rustemon::model::resource::Name;
+use rustemon::model::resource::VerboseEffect;
+
+use crate::cli::PokeApiOptions;
+use crate::cli::PokedexSourceKind;
+
+mod live;
+mod local;
+mod summary;
+
+pub use live::LivePokedex;
+pub use local::LocalPokedex;
+pub use summary::PokemonSummary;
+pub use summary::PokemonSummaryCache;
+
+/// A PokéAPI resource list that can be read from a [`PokedexSource`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    Pokemon,
+    PokemonSpecies,
+    Nature,
+    Region,
+    Move,
+    Ability,
+    Item,
+    Type,
+}
+
+impl Endpoint {
+    /// The path of the endpoint relative to `api/v2`.
+    pub fn path(&self) -> &'static str {
+        match self {
+            Endpoint::Pokemon => "pokemon",
+            Endpoint::PokemonSpecies => "pokemon-species",
+            Endpoint::Nature => "nature",
+            Endpoint::Region => "region",
+            Endpoint::Move => "move",
+            Endpoint::Ability => "ability",
+            Endpoint::Item => "item",
+            Endpoint::Type => "type",
+        }
+    }
+}
+
+/// The id and name of a resource in a PokéAPI resource list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PokedexEntry {
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PokedexError {
+    #[error(transparent)]
+    Rustemon(#[from] rustemon::error::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+/// A source of the Pokédex data shown by the PokéAPI commands.
+///
+/// Resources are looked up by their PokéAPI name, e.g. `mr-mime`, and lookups
+/// of unknown names return `None` rather than an error.
+#[async_trait::async_trait]
+pub trait PokedexSource: Send + Sync {
+    /// Returns every resource of an endpoint, ordered by id.
+    ///
+    /// The list is shared with the cache of the source, so it is cheap to call on every autocomplete request.
+    async fn entries(&self, endpoint: Endpoint) -> Result<Arc<[PokedexEntry]>, PokedexError>;
+
+    async fn pokemon(&self, name: &str) -> Result<Option<Pokemon>, PokedexError>;
+
+    async fn pokemon_species(&self, name: &str) -> Result<Option<PokemonSpecies>, PokedexError>;
+
+    async fn nature(&self, name: &str) -> Result<Option<Nature>, PokedexError>;
+
+    async fn region(&self, name: &str) -> Result<Option<Region>, PokedexError>;
+
+    async fn pokemon_move(&self, name: &str) -> Result<Option<Move>, PokedexError>;
+
+    async fn ability(&self, name: &str) -> Result<Option<Ability>, PokedexError>;
+
+    async fn item(&self, name: &str) -> Result<Option<Item>, PokedexError>;
+
+    async fn pokemon_type(&self, name: &str) -> Result<Option<Type>, PokedexError>;
+
+    /// Evolution chains have no names and are looked up by id instead.
+    async fn evolution_chain(&self, id: i64) -> Result<Option<EvolutionChain>, PokedexError>;
+}
+
+/// Creates the Pokédex source selected by the command line options.
+pub fn pokedex_source(options: &PokeApiOptions) -> Result<Box<dyn PokedexSource>, PokedexError> {
+    match (options.source, &options.local_directory) {
+        (PokedexSourceKind::Local, Some(local_directory)) => {
+            log::info!("Reading Pokédex data from {}.", local_directory.display());
+            Ok(Box::new(LocalPokedex::new(local_directory.clone())))
+        },
+        _ => {
+            live::spawn_cache_expiry(options);
+            Ok(Box::new(LivePokedex::new(options)?))
+        },
+    }
+}
+
+/// Parses the id of a resource from its PokéAPI url, e.g. `https://pokeapi.co/api/v2/pokemon/25/`.
+pub fn resource_id(url: &str) -> Option<i64> {
+    url.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .and_then(|id| id.parse().ok())
+}
+
+/// Converts a user provided name to the format PokéAPI uses for resource names, e.g. `Mr. Mime` to `mr-mime`.
+pub fn pokeapi_name(value: &str) -> String {
+    let mut name = String::with_capacity(value.len());
+
+    for character in value.trim().to_lowercase().chars() {
+        match character {
+            '.' | '\'' | '’' | ':' => {},
+            'é' | 'è' | 'ê' => name.push('e'),
+            '♀' => name.push_str("-f"),
+            '♂' => name.push_str("-m"),
+            character if character.is_whitespace() || character == '-' || character == '_' => {
+                if !name.ends_with('-') {
+                    name.push('-');
+                }
+            },
+            character => name.push(character),
+        }
+    }
+
+    name.trim_matches('-').to_string()
+}
+
+/// Converts a PokéAPI resource name to a readable name, e.g. `mr-mime` to `Mr Mime`.
+pub fn display_name(name: &str) -> String {
+    name.split('-')
+        .map(|word| {
+            let mut characters = word.chars();
+            match characters.next() {
+                Some(first) => first.to_uppercase().chain(characters).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn english_name(names: &[Name]) -> Option<String> {
+    names.iter()
+        .find(|name| name.language.name == "en")
+        .map(|name| name.name.clone())
+}
+
+/// Returns the short English description of an effect, falling back to the full description.
+pub fn english_effect(effect_entries: &[VerboseEffect]) -> Option<String> {
+    effect_entries.iter()
+        .find(|effect| effect.language.name == "en")
+        .map(|effect| if effect.short_effect.is_empty() { effect.effect.clone() } else { effect.short_effect.clone() })
+}
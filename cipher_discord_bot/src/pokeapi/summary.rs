@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
+use std::time::Instant;
+
+use super::display_name;
+use super::english_name;
+use super::pokeapi_name;
+use super::PokedexError;
+use super::PokedexSource;
+
+/// The details of a Pokémon shown alongside a profile's partner Pokémon.
+#[derive(Debug, Clone)]
+pub struct PokemonSummary {
+    pub name: String,
+    pub dex_number: i64,
+    pub types: Vec<String>,
+    pub sprite_url: Option<String>,
+}
+
+/// Looks up Pokémon summaries by name, remembering results so profiles can be shown
+/// repeatedly without querying PokéAPI every time.
+///
+/// Names that PokéAPI does not recognise are remembered as well. Network errors are not.
+pub struct PokemonSummaryCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, Option<PokemonSummary>)>>,
+}
+
+impl PokemonSummaryCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the summary of the Pokémon species with the given user provided name,
+    /// or `None` if it is not a known Pokémon or PokéAPI could not be reached.
+    pub async fn get(&self, pokedex: &dyn PokedexSource, value: &str) -> Option<PokemonSummary> {
+        let name = pokeapi_name(value);
+        if name.is_empty() {
+            return None;
+        }
+
+        if let Some((fetched_at, option_summary)) = self.entries.lock().unwrap_or_else(PoisonError::into_inner).get(&name) {
+            if fetched_at.elapsed() < self.ttl {
+                return option_summary.clone();
+            }
+        }
+
+        let option_summary = match Self::fetch(pokedex, &name).await {
+            Ok(option_summary) => option_summary,
+            Err(err) => {
+                log::warn!("Failed to look up Pokémon {} on PokéAPI: {}", name, err);
+                return None;
+            },
+        };
+
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.retain(|_, (fetched_at, _)| fetched_at.elapsed() < self.ttl);
+        entries.insert(name, (Instant::now(), option_summary.clone()));
+
+        option_summary
+    }
+
+    async fn fetch(pokedex: &dyn PokedexSource, name: &str) -> Result<Option<PokemonSummary>, PokedexError> {
+        let species = match pokedex.pokemon_species(name).await? {
+            Some(species) => species,
+            None => return Ok(None),
+        };
+
+        let variety = match species.varieties.iter().find(|variety| variety.is_default).or(species.varieties.first()) {
+            Some(variety) => variety,
+            None => return Ok(None),
+        };
+
+        let pokemon = match pokedex.pokemon(&variety.pokemon.name).await? {
+            Some(pokemon) => pokemon,
+            None => return Ok(None),
+        };
+
+        let mut types = pokemon.types;
+        types.sort_by_key(|pokemon_type| pokemon_type.slot);
+
+        let sprite_url = pokemon.sprites.other.official_artwork.front_default
+            .or(pokemon.sprites.front_default);
+
+        Ok(Some(PokemonSummary {
+            name: english_name(&species.names).unwrap_or_else(|| display_name(&species.name)),
+            dex_number: species.id,
+            types: types.iter().map(|pokemon_type| display_name(&pokemon_type.type_.name)).collect(),
+            sprite_url,
+        }))
+    }
+}
@@ -1,4 +1,7 @@
+use std::collections::HashSet;
+
 use cipher_core::repository::RepositoryProvider;
+use futures::StreamExt;
 use serenity::all::Color;
 use serenity::all::GuildId;
 use serenity::all::Member;
@@ -63,3 +66,135 @@ pub fn member_avatar_url(member: &Member) -> String {
         .or_else(|| member.user.static_avatar_url())
         .unwrap_or_else(|| member.user.default_avatar_url())
 }
+
+/// Returns the user ids of every member of a guild.
+///
+/// Members are read from the gateway cache once every member of the guild has been
+/// received. Until then they are fetched in pages over HTTP, which may take several
+/// seconds in large guilds.
+pub async fn guild_member_ids<R>(ctx: &AppContext<'_, R, R::BackendError>, guild_id: GuildId) -> Result<HashSet<u64>, AppError<R::BackendError>>
+where
+    R: RepositoryProvider + Send + Sync,
+{
+    if let Some(guild) = guild_id.to_guild_cached(ctx) {
+        if guild.members.len() as u64 >= guild.member_count {
+            return Ok(guild.members.keys().map(|user_id| user_id.get()).collect());
+        }
+    }
+
+    let mut members = guild_id.members_iter(ctx).boxed();
+    let mut member_ids = HashSet::new();
+
+    while let Some(member) = members.next().await {
+        member_ids.insert(member?.user.id.get());
+    }
+
+    Ok(member_ids)
+}
+
+/// Returns up to `limit` candidates that are most similar to `query`, most similar first.
+///
+/// Similarity is measured by the edit distance between lower case values. Candidates
+/// that share little with the query are omitted, and equally similar candidates keep
+/// their original order.
+pub fn closest_matches<'a, I>(query: &str, candidates: I, limit: usize) -> Vec<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let query = query.to_lowercase();
+    let max_distance = (query.chars().count() / 2).max(2);
+
+    let mut matches: Vec<_> = candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(&query, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+
+    matches.sort_by_key(|(distance, _)| *distance);
+
+    matches
+        .into_iter()
+        .take(limit)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// Returns the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    let mut current_row = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current_row[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution_cost = usize::from(a_char != *b_char);
+            current_row[j + 1] = (previous_row[j] + substitution_cost)
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+        }
+
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    previous_row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::closest_matches;
+    use super::edit_distance;
+
+    #[test]
+    fn edit_distance_counts_single_character_edits() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("pikachu", "pikachu"), 0);
+    }
+
+    #[test]
+    fn edit_distance_compares_characters_not_bytes() {
+        assert_eq!(edit_distance("pokémon", "pokemon"), 1);
+        assert_eq!(edit_distance("flabébé", "flabebe"), 2);
+        assert_eq!(edit_distance("ニャース", "ニャスパー"), 3);
+    }
+
+    #[test]
+    fn closest_matches_orders_by_similarity() {
+        let candidates = ["charizard", "charmander", "charmeleon", "bulbasaur"];
+
+        assert_eq!(closest_matches("charmandr", candidates, 3), vec!["charmander", "charizard"]);
+    }
+
+    #[test]
+    fn closest_matches_keeps_candidate_order_for_ties() {
+        assert_eq!(closest_matches("mex", ["mew", "hex", "muk"], 3), vec!["mew", "hex", "muk"]);
+        assert_eq!(closest_matches("mex", ["hex", "mew", "muk"], 3), vec!["hex", "mew", "muk"]);
+    }
+
+    #[test]
+    fn closest_matches_caps_results_at_limit() {
+        let candidates = ["few", "mow", "mew", "new"];
+
+        assert_eq!(closest_matches("mew", candidates, 2), vec!["mew", "few"]);
+        assert!(closest_matches("mew", candidates, 0).is_empty());
+    }
+
+    #[test]
+    fn closest_matches_with_empty_query_only_returns_short_candidates() {
+        let candidates = ["mew", "io", "a", ""];
+
+        assert_eq!(closest_matches("", candidates, 10), vec!["", "a", "io"]);
+    }
+
+    #[test]
+    fn closest_matches_ignores_case_of_non_ascii_characters() {
+        let candidates = ["Flabébé", "Floette", "Florges"];
+
+        assert_eq!(closest_matches("FLABÉBÉ", candidates, 3), vec!["Flabébé"]);
+        assert_eq!(closest_matches("flabebe", candidates, 1), vec!["Flabébé"]);
+    }
+}